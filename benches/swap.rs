@@ -0,0 +1,42 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use uniswap_sdk_core::{prelude::*, token};
+use uniswap_v3_sdk::prelude::*;
+
+const LIQUIDITY: u128 = 1_000_000_000_000_000_000;
+
+/// A pool with 250 nested positions centered on tick 0, i.e. 500 initialized ticks.
+fn dense_pool() -> Pool<TickListDataProvider> {
+    let tick_spacing = FeeAmount::MEDIUM.tick_spacing().as_i32();
+    let mut ticks = Vec::with_capacity(500);
+    for i in (1..=250).rev() {
+        ticks.push(Tick::new(-tick_spacing * i, LIQUIDITY, LIQUIDITY as i128));
+    }
+    for i in 1..=250 {
+        ticks.push(Tick::new(tick_spacing * i, LIQUIDITY, -(LIQUIDITY as i128)));
+    }
+    Pool::new_with_tick_data_provider(
+        token!(1, "0000000000000000000000000000000000000001", 18, "t0"),
+        token!(1, "0000000000000000000000000000000000000002", 18, "t1"),
+        FeeAmount::MEDIUM,
+        encode_sqrt_ratio_x96(1, 1),
+        250 * LIQUIDITY,
+        TickListDataProvider::new(ticks, tick_spacing),
+    )
+    .unwrap()
+}
+
+fn get_output_amount_benchmark(c: &mut Criterion) {
+    let pool = dense_pool();
+    let amount = BigInt::from(10).pow(20);
+    let amount0 = CurrencyAmount::from_raw_amount(pool.token0.clone(), amount).unwrap();
+    let amount1 = CurrencyAmount::from_raw_amount(pool.token1.clone(), amount).unwrap();
+    c.bench_function("get_output_amount_dense_pool", |b| {
+        b.iter(|| {
+            let _ = pool.get_output_amount(&amount0, None);
+            let _ = pool.get_output_amount(&amount1, None);
+        })
+    });
+}
+
+criterion_group!(benches, get_output_amount_benchmark);
+criterion_main!(benches);
@@ -47,7 +47,7 @@ async fn main() {
 
     // Get the output amount from the quoter
     let route = Route::new(vec![pool], wbtc, weth);
-    let params = quote_call_parameters(&route, &amount_in, TradeType::ExactInput, None);
+    let params = quote_call_parameters(&route, &amount_in, TradeType::ExactInput, None).unwrap();
     let tx = TransactionRequest::default()
         .to(*QUOTER_ADDRESSES.get(&1).unwrap())
         .input(params.calldata.into());
@@ -6,6 +6,31 @@ sol! {
     }
 }
 
+sol! {
+    interface IMulticallExtended {
+        function multicall(uint256 deadline, bytes[] calldata data) external payable returns (bytes[] memory results);
+    }
+}
+
+sol! {
+    interface IMulticall3 {
+        struct Call {
+            address target;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function tryAggregate(bool requireSuccess, Call[] calldata calls)
+            external
+            payable
+            returns (Result[] memory returnData);
+    }
+}
+
 sol! {
     interface INonfungiblePositionManager {
         function createAndInitializePoolIfNecessary(
@@ -85,6 +110,24 @@ sol! {
 
         function burn(uint256 tokenId) external payable;
 
+        function positions(uint256 tokenId)
+            external
+            view
+            returns (
+                uint96 nonce,
+                address operator,
+                address token0,
+                address token1,
+                uint24 fee,
+                int24 tickLower,
+                int24 tickUpper,
+                uint128 liquidity,
+                uint256 feeGrowthInside0LastX128,
+                uint256 feeGrowthInside1LastX128,
+                uint128 tokensOwed0,
+                uint128 tokensOwed1
+            );
+
         function safeTransferFrom(address from, address to, uint256 tokenId) external;
 
         function safeTransferFrom(address from, address to, uint256 tokenId, bytes calldata data) external;
@@ -114,6 +157,34 @@ sol! {
         function selfPermitAllowed(address token, uint256 nonce, uint256 expiry, uint8 v, bytes32 r, bytes32 s) external payable;
     }
 
+    interface IAllowanceTransfer {
+        #[derive(Debug, Default, PartialEq, Eq)]
+        struct PermitDetails {
+            address token;
+            uint160 amount;
+            uint48 expiration;
+            uint48 nonce;
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq)]
+        struct PermitSingle {
+            PermitDetails details;
+            address spender;
+            uint256 sigDeadline;
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq)]
+        struct PermitBatch {
+            PermitDetails[] details;
+            address spender;
+            uint256 sigDeadline;
+        }
+
+        function permit(address owner, PermitSingle memory permitSingle, bytes calldata signature) external;
+
+        function permit(address owner, PermitBatch memory permitBatch, bytes calldata signature) external;
+    }
+
     interface IERC20Permit {
         #[derive(Debug, Default, PartialEq, Eq)]
         struct Permit {
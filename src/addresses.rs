@@ -0,0 +1,166 @@
+//! ## Addresses
+//! Canonical Uniswap V3 deployment addresses keyed by chain id, gathered from the address maps of
+//! `uniswap-sdk-core`.
+
+use alloy_primitives::{address, Address, ChainId};
+use uniswap_sdk_core::prelude::{
+    BaseCurrency, NONFUNGIBLE_POSITION_MANAGER_ADDRESSES, QUOTER_ADDRESSES,
+    SWAP_ROUTER_02_ADDRESSES, V3_CORE_FACTORY_ADDRESSES, WETH9,
+};
+
+const STAKER: Address = address!("e34139463bA50bD61336E0c446Bd8C0867c6fE65");
+
+/// The known `UniswapV3Staker` deployments keyed by chain id.
+pub const CHAIN_TO_STAKER_ADDRESS: [(ChainId, Address); 4] = [
+    // Ethereum
+    (1, STAKER),
+    // Optimism
+    (10, STAKER),
+    // Polygon
+    (137, STAKER),
+    // Arbitrum One
+    (42161, STAKER),
+];
+
+/// The canonical Uniswap V3 deployment addresses on a chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChainAddresses {
+    /// The wrapped native currency used by the periphery contracts, e.g. WETH9 on Ethereum.
+    pub weth9: Address,
+    /// The `UniswapV3Factory` contract.
+    pub factory: Address,
+    /// The `NonfungiblePositionManager` contract.
+    pub nonfungible_position_manager: Address,
+    /// The `SwapRouter02` contract.
+    pub swap_router: Address,
+    /// The quoter contract.
+    pub quoter: Address,
+    /// The `UniswapV3Staker` contract, if deployed on the chain.
+    pub staker: Option<Address>,
+}
+
+/// Returns the known `UniswapV3Staker` deployment on the given chain, if any
+///
+/// ## Arguments
+///
+/// * `chain_id`: The chain id
+#[inline]
+#[must_use]
+pub fn resolve_staker_address(chain_id: ChainId) -> Option<Address> {
+    CHAIN_TO_STAKER_ADDRESS
+        .iter()
+        .find(|(id, _)| *id == chain_id)
+        .map(|(_, staker)| *staker)
+}
+
+/// Returns the canonical Uniswap V3 deployment addresses for the given chain
+///
+/// ## Arguments
+///
+/// * `chain_id`: The chain id
+///
+/// ## Returns
+///
+/// The deployment addresses, or `None` if `uniswap-sdk-core` doesn't know the periphery
+/// deployments or the wrapped native currency of the chain
+///
+/// ## Examples
+///
+/// ```
+/// use uniswap_v3_sdk::prelude::*;
+///
+/// let addresses = chain_addresses(1).unwrap();
+/// assert_eq!(addresses.factory, FACTORY_ADDRESS);
+/// assert!(chain_addresses(0).is_none());
+/// ```
+#[inline]
+#[must_use]
+pub fn chain_addresses(chain_id: ChainId) -> Option<ChainAddresses> {
+    Some(ChainAddresses {
+        weth9: WETH9::on_chain(chain_id)?.address(),
+        factory: *V3_CORE_FACTORY_ADDRESSES.get(&chain_id)?,
+        nonfungible_position_manager: *NONFUNGIBLE_POSITION_MANAGER_ADDRESSES.get(&chain_id)?,
+        swap_router: *SWAP_ROUTER_02_ADDRESSES.get(&chain_id)?,
+        quoter: *QUOTER_ADDRESSES.get(&chain_id)?,
+        staker: resolve_staker_address(chain_id),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::FACTORY_ADDRESS;
+
+    #[test]
+    fn mainnet() {
+        let addresses = chain_addresses(1).unwrap();
+        assert_eq!(
+            addresses.weth9,
+            address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")
+        );
+        assert_eq!(addresses.factory, FACTORY_ADDRESS);
+        assert_eq!(
+            addresses.nonfungible_position_manager,
+            address!("C36442b4a4522E871399CD717aBDD847Ab11FE88")
+        );
+        assert_eq!(
+            addresses.swap_router,
+            address!("68b3465833fb72A70ecDF485E0e4C7bD8665Fc45")
+        );
+        assert_eq!(
+            addresses.quoter,
+            address!("b27308f9F90D607463bb33eA1BeBb41C27CE5AB6")
+        );
+        assert_eq!(
+            addresses.staker,
+            Some(address!("e34139463bA50bD61336E0c446Bd8C0867c6fE65"))
+        );
+    }
+
+    #[test]
+    fn arbitrum() {
+        let addresses = chain_addresses(42161).unwrap();
+        assert_eq!(
+            addresses.weth9,
+            address!("82aF49447D8a07e3bd95BD0d56f35241523fBab1")
+        );
+        assert_eq!(addresses.factory, FACTORY_ADDRESS);
+        assert_eq!(
+            addresses.swap_router,
+            address!("68b3465833fb72A70ecDF485E0e4C7bD8665Fc45")
+        );
+    }
+
+    #[test]
+    fn base() {
+        let addresses = chain_addresses(8453).unwrap();
+        assert_eq!(
+            addresses.weth9,
+            address!("4200000000000000000000000000000000000006")
+        );
+        assert_eq!(
+            addresses.factory,
+            address!("33128a8fC17869897dcE68Ed026d694621f6FDfD")
+        );
+        assert_eq!(
+            addresses.nonfungible_position_manager,
+            address!("03a520b32C04BF3bEEf7BEb72E919cf822Ed34f1")
+        );
+        assert_eq!(addresses.staker, None);
+    }
+
+    #[test]
+    fn unsupported_chain() {
+        assert_eq!(chain_addresses(0), None);
+        assert_eq!(resolve_staker_address(0), None);
+    }
+
+    #[test]
+    fn staker_address_table_matches_chain_addresses() {
+        for (chain_id, staker) in CHAIN_TO_STAKER_ADDRESS {
+            assert_eq!(chain_addresses(chain_id).unwrap().staker, Some(staker));
+            assert_eq!(resolve_staker_address(chain_id), Some(staker));
+        }
+        assert_eq!(resolve_staker_address(8453), None);
+    }
+}
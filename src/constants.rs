@@ -1,11 +1,21 @@
+use crate::error::Error;
 use alloy_primitives::{
     address,
     aliases::{I24, U24},
     b256, Address, B256,
 };
+use uniswap_sdk_core::prelude::{BigInt, Percent};
 
 pub const FACTORY_ADDRESS: Address = address!("1F98431c8aD98523631AE4a59f267346ea31F984");
 
+/// The address of the [`Multicall3`](https://www.multicall3.com) contract, deployed at the same
+/// address on most chains.
+pub const MULTICALL3_ADDRESS: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+/// The address of the [`Permit2`](https://github.com/Uniswap/permit2) contract, deployed at the
+/// same address on most chains.
+pub const PERMIT2_ADDRESS: Address = address!("000000000022D473030F116dDEE9F6B43aC78BA3");
+
 pub const POOL_INIT_CODE_HASH: B256 =
     b256!("e34f199b19b2b4f47f68442619d555527d244f78a3297ea89325f843f87b8b54");
 
@@ -40,6 +50,35 @@ impl FeeAmount {
             Self::CUSTOM(fee) => I24::from_limbs([(fee / 50) as u64]),
         }
     }
+
+    /// Returns the standard fee tier corresponding to a fee percentage, e.g. 0.3% for
+    /// [`FeeAmount::MEDIUM`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `percent`: The fee as a percentage of the swapped amount
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidFee`] if the percentage does not match a standard fee tier.
+    #[inline]
+    pub fn from_percent(percent: Percent) -> Result<Self, Error> {
+        [
+            Self::LOWEST,
+            Self::LOW_200,
+            Self::LOW_300,
+            Self::LOW_400,
+            Self::LOW,
+            Self::MEDIUM,
+            Self::HIGH,
+        ]
+        .into_iter()
+        .find(|&fee| {
+            BigInt::from(U24::from(fee).to::<u64>()) * percent.denominator
+                == percent.numerator * BigInt::from(1_000_000)
+        })
+        .ok_or(Error::InvalidFee)
+    }
 }
 
 impl From<u32> for FeeAmount {
@@ -96,3 +135,32 @@ impl From<U24> for FeeAmount {
         (fee.into_limbs()[0] as u32).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_percent_maps_standard_fee_tiers() {
+        assert_eq!(
+            FeeAmount::from_percent(Percent::new(5, 10000)).unwrap(),
+            FeeAmount::LOW
+        );
+        assert_eq!(
+            FeeAmount::from_percent(Percent::new(3, 1000)).unwrap(),
+            FeeAmount::MEDIUM
+        );
+        assert_eq!(
+            FeeAmount::from_percent(Percent::new(1, 100)).unwrap(),
+            FeeAmount::HIGH
+        );
+    }
+
+    #[test]
+    fn from_percent_errors_for_unsupported_fee() {
+        assert!(matches!(
+            FeeAmount::from_percent(Percent::new(2, 1000)),
+            Err(Error::InvalidFee)
+        ));
+    }
+}
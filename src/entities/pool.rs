@@ -1,5 +1,6 @@
 use crate::prelude::{Error, *};
-use alloy_primitives::{ChainId, B256, I256, U160};
+use alloy_primitives::{aliases::I24, ChainId, B256, I256, U160, U256};
+use num_traits::ToPrimitive;
 use uniswap_sdk_core::prelude::*;
 
 /// Represents a V3 pool
@@ -108,6 +109,33 @@ impl Pool {
             Some(token_a.chain_id()),
         )
     }
+
+    /// Computes the initial sqrt ratio of a pool from the intended starting amounts of its tokens
+    ///
+    /// ## Arguments
+    ///
+    /// * `amount0`: The starting amount of token0
+    /// * `amount1`: The starting amount of token1
+    ///
+    /// ## Returns
+    ///
+    /// The sqrt of the ratio of `amount1` to `amount0` as a Q64.96, or [`Error::ZeroAmount`] if
+    /// either amount is zero and [`Error::InvalidSqrtPrice`] if the ratio is outside the range
+    /// supported by the pool
+    #[inline]
+    pub fn initial_sqrt_ratio_from_amounts(amount0: U256, amount1: U256) -> Result<U160, Error> {
+        if amount0.is_zero() || amount1.is_zero() {
+            return Err(Error::ZeroAmount);
+        }
+        let sqrt_ratio_x96: U256 =
+            encode_sqrt_ratio_x96(amount1.to_big_int(), amount0.to_big_int());
+        if sqrt_ratio_x96 < U256::from(MIN_SQRT_RATIO)
+            || sqrt_ratio_x96 >= U256::from(MAX_SQRT_RATIO)
+        {
+            return Err(Error::InvalidSqrtPrice(sqrt_ratio_x96.saturating_to()));
+        }
+        Ok(U160::from(sqrt_ratio_x96))
+    }
 }
 
 impl<TP: TickDataProvider> Pool<TP> {
@@ -149,6 +177,25 @@ impl<TP: TickDataProvider> Pool<TP> {
         self.token0.equals(token) || self.token1.equals(token)
     }
 
+    /// Returns the other token of the pool, matching a native currency by its wrapped token
+    ///
+    /// ## Arguments
+    ///
+    /// * `token`: One of the tokens in the pool
+    ///
+    /// returns: The opposite token, or `None` if `token` is not in the pool
+    #[inline]
+    pub fn other_token(&self, token: &impl BaseCurrency) -> Option<&Token> {
+        let token = token.wrapped();
+        if self.token0.equals(token) {
+            Some(&self.token1)
+        } else if self.token1.equals(token) {
+            Some(&self.token0)
+        } else {
+            None
+        }
+    }
+
     /// Returns the current mid price of the pool in terms of token0, i.e. the ratio of token1 over
     /// token0
     #[inline]
@@ -193,6 +240,124 @@ impl<TP: TickDataProvider> Pool<TP> {
         }
     }
 
+    /// Returns the prices of token0 in terms of token1 at each of the given ticks
+    ///
+    /// ## Arguments
+    ///
+    /// * `ticks`: The ticks to return prices at, each between [`MIN_TICK`] and [`MAX_TICK`]
+    ///
+    /// returns: The token0 prices, in the order of `ticks`
+    #[inline]
+    pub fn prices_at_ticks(&self, ticks: &[i32]) -> Result<Vec<Price<Token, Token>>, Error> {
+        ticks
+            .iter()
+            .map(|&tick| {
+                let tick =
+                    I24::try_from(tick).unwrap_or(if tick < 0 { I24::MIN } else { I24::MAX });
+                tick_to_price(self.token0.clone(), self.token1.clone(), tick)
+            })
+            .collect()
+    }
+
+    /// Returns the usable tick of the pool closest to the given price, snapped to the tick spacing
+    /// with the given rounding
+    ///
+    /// ## Arguments
+    ///
+    /// * `price`: The price between the pool tokens, in either orientation
+    /// * `rounding`: Whether to snap down, up or to the nearest usable tick
+    ///
+    /// returns: The usable tick
+    #[inline]
+    pub fn tick_for_price(
+        &self,
+        price: &Price<Token, Token>,
+        rounding: Rounding,
+    ) -> Result<i32, Error> {
+        if !self.involves_token(&price.base_currency) || !self.involves_token(&price.quote_currency)
+        {
+            return Err(Error::InvalidToken);
+        }
+        let tick = price_to_closest_tick(price)?.as_i32();
+        let tick_spacing = self.tick_spacing().to_i24().as_i32();
+        let tick = match rounding {
+            Rounding::RoundDown => tick.div_euclid(tick_spacing) * tick_spacing,
+            Rounding::RoundUp => -(-tick).div_euclid(tick_spacing) * tick_spacing,
+            Rounding::RoundHalfUp => nearest_usable_tick(tick, tick_spacing),
+        };
+        Ok(tick.clamp(
+            nearest_usable_tick(MIN_TICK_I32, tick_spacing),
+            nearest_usable_tick(MAX_TICK_I32, tick_spacing),
+        ))
+    }
+
+    /// Returns the virtual reserves of token0 and token1 implied by the current in range liquidity
+    /// and price, i.e. `L / sqrt(P)` and `L * sqrt(P)`
+    #[inline]
+    pub fn virtual_reserves(&self) -> Result<(U256, U256), Error> {
+        let liquidity = U256::from(self.liquidity);
+        let sqrt_ratio_x96 = U256::from(self.sqrt_ratio_x96);
+        Ok((
+            mul_div(liquidity, Q96, sqrt_ratio_x96)?,
+            mul_div(liquidity, sqrt_ratio_x96, Q96)?,
+        ))
+    }
+
+    /// Returns the [`Pool::virtual_reserves`] as amounts of token0 and token1
+    #[inline]
+    pub fn reserves(&self) -> Result<(CurrencyAmount<Token>, CurrencyAmount<Token>), Error> {
+        let (reserve0, reserve1) = self.virtual_reserves()?;
+        Ok((
+            CurrencyAmount::from_raw_amount(self.token0.clone(), reserve0.to_big_int())?,
+            CurrencyAmount::from_raw_amount(self.token1.clone(), reserve1.to_big_int())?,
+        ))
+    }
+
+    /// Computes the amounts and liquidity of a full range mint at the current price given the
+    /// amount of one of the tokens, filling in the amount of the other token
+    ///
+    /// ## Arguments
+    ///
+    /// * `amount0`: The amount of token0 to mint with, if specified
+    /// * `amount1`: The amount of token1 to mint with, if specified
+    ///
+    /// returns: The amounts of token0 and token1 required to mint the liquidity, and the liquidity
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidAmounts`] unless exactly one of the amounts is specified, and
+    /// [`Error::SafeCastToU128Overflow`] if the resulting liquidity does not fit in a `u128`.
+    #[inline]
+    pub fn full_range_amounts(
+        &self,
+        amount0: Option<U256>,
+        amount1: Option<U256>,
+    ) -> Result<(U256, U256, u128), Error> {
+        if amount0.is_some() == amount1.is_some() {
+            return Err(Error::InvalidAmounts);
+        }
+        let tick_spacing = self.tick_spacing().to_i24();
+        let sqrt_ratio_lower_x96 =
+            get_sqrt_ratio_at_tick(nearest_usable_tick(MIN_TICK, tick_spacing))?;
+        let sqrt_ratio_upper_x96 =
+            get_sqrt_ratio_at_tick(nearest_usable_tick(MAX_TICK, tick_spacing))?;
+        let liquidity = max_liquidity_for_amounts(
+            self.sqrt_ratio_x96,
+            sqrt_ratio_lower_x96,
+            sqrt_ratio_upper_x96,
+            amount0.unwrap_or(U256::MAX),
+            amount1.unwrap_or(U256::MAX),
+            true,
+        )
+        .to_u128()
+        .ok_or(Error::SafeCastToU128Overflow)?;
+        Ok((
+            get_amount_0_delta(self.sqrt_ratio_x96, sqrt_ratio_upper_x96, liquidity, true)?,
+            get_amount_1_delta(sqrt_ratio_lower_x96, self.sqrt_ratio_x96, liquidity, true)?,
+            liquidity,
+        ))
+    }
+
     /// Construct a pool with a tick data provider
     ///
     /// ## Arguments
@@ -230,6 +395,25 @@ impl<TP: TickDataProvider> Pool<TP> {
         })
     }
 
+    /// Checks that a price limit is strictly between the current price and the bound of the swap
+    /// direction, as the pool requires
+    fn validate_sqrt_price_limit(
+        &self,
+        zero_for_one: bool,
+        sqrt_price_limit_x96: U160,
+    ) -> Result<(), Error> {
+        let limit_is_valid = if zero_for_one {
+            sqrt_price_limit_x96 > MIN_SQRT_RATIO && sqrt_price_limit_x96 < self.sqrt_ratio_x96
+        } else {
+            sqrt_price_limit_x96 < MAX_SQRT_RATIO && sqrt_price_limit_x96 > self.sqrt_ratio_x96
+        };
+        if limit_is_valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidSqrtPrice(sqrt_price_limit_x96))
+        }
+    }
+
     fn _swap(
         &self,
         zero_for_one: bool,
@@ -249,6 +433,120 @@ impl<TP: TickDataProvider> Pool<TP> {
         )
     }
 
+    /// Simulates a swap of `amount`, the input of an exact input swap or the output of an exact
+    /// output swap, through `tick_data_provider` and returns its direction and final state
+    ///
+    /// The price limit is validated when given. Running out of liquidity is an error unless the
+    /// swap was halted by the price limit or truncated by `max_ticks_crossed`, in which case
+    /// `amount_specified_remaining` holds the unfilled amount.
+    fn simulate_swap<P>(
+        &self,
+        amount: &CurrencyAmount<impl BaseCurrency>,
+        exact_input: bool,
+        sqrt_price_limit_x96: Option<U160>,
+        tick_data_provider: &P,
+        max_ticks_crossed: Option<u32>,
+        trace: Option<&mut Vec<SwapStep<TP::Index>>>,
+    ) -> Result<(bool, SwapState<TP::Index>), Error>
+    where
+        P: TickDataProvider<Index = TP::Index>,
+    {
+        if !self.involves_token(&amount.currency) {
+            return Err(Error::InvalidToken);
+        }
+
+        let zero_for_one = amount.currency.equals(if exact_input {
+            &self.token0
+        } else {
+            &self.token1
+        });
+        if let Some(sqrt_price_limit_x96) = sqrt_price_limit_x96 {
+            self.validate_sqrt_price_limit(zero_for_one, sqrt_price_limit_x96)?;
+        }
+
+        let amount_specified = if exact_input {
+            I256::from_big_int(amount.quotient())
+        } else {
+            I256::from_big_int(-amount.quotient())
+        };
+        let state = swap_loop(
+            self.fee.into(),
+            self.sqrt_ratio_x96,
+            self.tick_current,
+            self.liquidity,
+            self.tick_spacing(),
+            tick_data_provider,
+            zero_for_one,
+            amount_specified,
+            sqrt_price_limit_x96,
+            max_ticks_crossed,
+            trace,
+        )?;
+
+        if !state.amount_specified_remaining.is_zero()
+            && sqrt_price_limit_x96.is_none()
+            && !state.truncated
+        {
+            return Err(Error::InsufficientLiquidity);
+        }
+        Ok((zero_for_one, state))
+    }
+
+    /// Moves the pool to the state a simulated swap ended in
+    ///
+    /// The swap loop leaves the tick one below an initialized tick it crossed while moving
+    /// leftward and landed on exactly, matching `slot0.tick` on-chain.
+    fn apply_swap_state(&mut self, state: &SwapState<TP::Index>) {
+        self.sqrt_ratio_x96 = state.sqrt_price_x96;
+        self.tick_current = state.tick_current;
+        self.liquidity = state.liquidity;
+    }
+
+    /// Returns the input and output tokens of a swap in the given direction
+    #[inline]
+    const fn swap_tokens(&self, zero_for_one: bool) -> (&Token, &Token) {
+        if zero_for_one {
+            (&self.token0, &self.token1)
+        } else {
+            (&self.token1, &self.token0)
+        }
+    }
+
+    /// Simulates a swap and returns the pool's balance deltas with the sign convention of
+    /// `UniswapV3Pool.swap` and its `Swap` event: positive amounts are paid into the pool and
+    /// negative amounts are paid out of it
+    ///
+    /// ## Arguments
+    ///
+    /// * `amount_specified`: The amount of the swap, which implicitly configures the swap as exact
+    ///   input (positive), or exact output (negative)
+    /// * `zero_for_one`: The direction of the swap, true for token0 to token1, false for token1 to
+    ///   token0
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit
+    ///
+    /// returns: The signed `(amount0, amount1)` deltas
+    #[inline]
+    pub fn swap_deltas(
+        &self,
+        amount_specified: I256,
+        zero_for_one: bool,
+        sqrt_price_limit_x96: Option<U160>,
+    ) -> Result<(I256, I256), Error> {
+        let SwapState {
+            amount_specified_remaining,
+            amount_calculated,
+            ..
+        } = self._swap(zero_for_one, amount_specified, sqrt_price_limit_x96)?;
+
+        let amount_filled = amount_specified - amount_specified_remaining;
+        let exact_input = amount_specified >= I256::ZERO;
+        Ok(if zero_for_one == exact_input {
+            (amount_filled, amount_calculated)
+        } else {
+            (amount_calculated, amount_filled)
+        })
+    }
+
     /// Given an input amount of a token, return the computed output amount
     ///
     /// ## Arguments
@@ -263,33 +561,397 @@ impl<TP: TickDataProvider> Pool<TP> {
         input_amount: &CurrencyAmount<impl BaseCurrency>,
         sqrt_price_limit_x96: Option<U160>,
     ) -> Result<CurrencyAmount<Token>, Error> {
-        if !self.involves_token(&input_amount.currency) {
-            return Err(Error::InvalidToken);
-        }
+        self.get_output_amount_with_provider(
+            input_amount,
+            sqrt_price_limit_x96,
+            &self.tick_data_provider,
+        )
+    }
 
-        let zero_for_one = input_amount.currency.equals(&self.token0);
+    /// Given an input amount of a token, return the computed output amount, simulating the swap
+    /// against the given tick data provider instead of the pool's own
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_amount`: The input amount for which to quote the output amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit
+    /// * `tick_data_provider`: The tick data snapshot to swap through
+    ///
+    /// returns: The output amount
+    #[inline]
+    pub fn get_output_amount_with_provider<P>(
+        &self,
+        input_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: Option<U160>,
+        tick_data_provider: &P,
+    ) -> Result<CurrencyAmount<Token>, Error>
+    where
+        P: TickDataProvider<Index = TP::Index>,
+    {
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
+            sqrt_price_limit_x96,
+            tick_data_provider,
+            None,
+            None,
+        )?;
+        let (_, output_token) = self.swap_tokens(zero_for_one);
+        CurrencyAmount::from_raw_amount(output_token.clone(), -state.amount_calculated.to_big_int())
+            .map_err(Error::Core)
+    }
 
-        let SwapState {
-            amount_specified_remaining,
-            amount_calculated: output_amount,
-            ..
-        } = self._swap(
-            zero_for_one,
-            I256::from_big_int(input_amount.quotient()),
+    /// Given an input amount of a token and a price limit, return the computed output amount and
+    /// the input amount left unfilled once the limit is reached, matching what the router returns
+    /// when `sqrtPriceLimitX96` halts the swap early
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_amount`: The input amount for which to quote the output amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit. If zero for one, the price cannot be
+    ///   less than this value after the swap. If one for zero, the price cannot be greater than
+    ///   this value after the swap
+    ///
+    /// returns: The output amount and the remaining input amount
+    #[inline]
+    pub fn get_output_amount_with_limit(
+        &self,
+        input_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: U160,
+    ) -> Result<(CurrencyAmount<Token>, CurrencyAmount<Token>), Error> {
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
+            Some(sqrt_price_limit_x96),
+            &self.tick_data_provider,
+            None,
+            None,
+        )?;
+        let (input_token, output_token) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                output_token.clone(),
+                -state.amount_calculated.to_big_int(),
+            )?,
+            CurrencyAmount::from_raw_amount(
+                input_token.clone(),
+                state.amount_specified_remaining.to_big_int(),
+            )?,
+        ))
+    }
+
+    /// Given an input amount of a token, return the computed output amount and the fee paid in the
+    /// input token, accrued to the liquidity providers
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_amount`: The input amount for which to quote the output amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit
+    ///
+    /// returns: The output amount and the fee amount
+    #[inline]
+    pub fn get_output_amount_with_fee(
+        &self,
+        input_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: Option<U160>,
+    ) -> Result<(CurrencyAmount<Token>, CurrencyAmount<Token>), Error> {
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
             sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            None,
         )?;
+        let (input_token, output_token) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                output_token.clone(),
+                -state.amount_calculated.to_big_int(),
+            )?,
+            CurrencyAmount::from_raw_amount(input_token.clone(), state.fee_amount.to_big_int())?,
+        ))
+    }
 
-        if !amount_specified_remaining.is_zero() && sqrt_price_limit_x96.is_none() {
-            return Err(Error::InsufficientLiquidity);
+    /// Given an input amount of a token, return the computed output amount and the number of
+    /// initialized ticks crossed by the swap loop
+    ///
+    /// This is not the `initializedTicksCrossed` of the QuoterV2 contract, which counts the
+    /// initialized ticks between the start and end tick of the swap rather than the tick
+    /// transitions of the loop, so the two can differ, e.g. when the swap starts or ends exactly on
+    /// an initialized tick.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_amount`: The input amount for which to quote the output amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit
+    ///
+    /// returns: The output amount and the number of initialized ticks crossed
+    #[inline]
+    pub fn get_output_amount_detailed(
+        &self,
+        input_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: Option<U160>,
+    ) -> Result<(CurrencyAmount<Token>, u32), Error> {
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
+            sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            None,
+        )?;
+        let (_, output_token) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                output_token.clone(),
+                -state.amount_calculated.to_big_int(),
+            )?,
+            state.initialized_ticks_crossed,
+        ))
+    }
+
+    /// Given an input amount of a token, return the computed output amount and every step of the
+    /// swap loop, e.g. to chart the price impact across the curve
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_amount`: The input amount for which to quote the output amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit
+    ///
+    /// returns: The output amount and the swap steps. If the swap is halted by
+    /// `sqrt_price_limit_x96`, the output amount only covers the part of the input filled before
+    /// the limit and the unfilled input is not returned, see
+    /// [`Pool::get_output_amount_with_limit`] to get it
+    #[inline]
+    pub fn get_output_amount_with_trace(
+        &self,
+        input_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: Option<U160>,
+    ) -> Result<(CurrencyAmount<Token>, Vec<SwapStep<TP::Index>>), Error> {
+        let mut steps = Vec::new();
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
+            sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            Some(&mut steps),
+        )?;
+        let (_, output_token) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                output_token.clone(),
+                -state.amount_calculated.to_big_int(),
+            )?,
+            steps,
+        ))
+    }
+
+    /// Given an input amount of a token, return the computed output amount, crossing at most
+    /// `max_ticks` initialized ticks
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_amount`: The input amount for which to quote the output amount
+    /// * `max_ticks`: The maximum number of initialized ticks the simulation may cross
+    ///
+    /// returns: The output amount and whether the swap was truncated by `max_ticks`
+    #[inline]
+    pub fn get_output_amount_bounded(
+        &self,
+        input_amount: &CurrencyAmount<impl BaseCurrency>,
+        max_ticks: u32,
+    ) -> Result<(CurrencyAmount<Token>, bool), Error> {
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
+            None,
+            &self.tick_data_provider,
+            Some(max_ticks),
+            None,
+        )?;
+        let (_, output_token) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                output_token.clone(),
+                -state.amount_calculated.to_big_int(),
+            )?,
+            state.truncated,
+        ))
+    }
+
+    /// Given an exact input amount, return the output amount assuming the swap stays within the
+    /// current tick, i.e. with the current liquidity and a single swap step. This is a fast
+    /// approximation that only matches [`Pool::get_output_amount`] for swaps that cross no
+    /// initialized tick.
+    ///
+    /// ## Arguments
+    ///
+    /// * `amount_in`: The raw input amount
+    /// * `zero_for_one`: Whether the input is token0
+    ///
+    /// returns: The raw output amount, or [`Error::InvalidAmounts`] if `amount_in` does not fit
+    /// in an `int256`
+    #[inline]
+    pub fn amount_out_within_tick(
+        &self,
+        amount_in: U256,
+        zero_for_one: bool,
+    ) -> Result<U256, Error> {
+        let amount_remaining = I256::try_from(amount_in).map_err(|_| Error::InvalidAmounts)?;
+        let sqrt_ratio_target_x96 = if zero_for_one {
+            MIN_SQRT_RATIO + ONE
+        } else {
+            MAX_SQRT_RATIO - ONE
+        };
+        let (_, _, amount_out, _) = compute_swap_step(
+            self.sqrt_ratio_x96,
+            sqrt_ratio_target_x96,
+            self.liquidity,
+            amount_remaining,
+            self.fee.into(),
+        )?;
+        Ok(amount_out)
+    }
+
+    /// Returns the price impact of an exact input swap assuming the current liquidity holds across
+    /// the whole swap, see [`Pool::amount_out_within_tick`]. Matches [`Trade::price_impact`] of a
+    /// single-pool trade that crosses no initialized tick.
+    ///
+    /// Both are computed as the exact fraction `(spot_output - output) / spot_output` without
+    /// intermediate rounding and include the LP fee, so for such swaps they are equal rather than
+    /// merely close, and both are positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `amount_in`: The raw input amount
+    /// * `zero_for_one`: Whether the input is token0
+    #[inline]
+    pub fn approximate_price_impact(
+        &self,
+        amount_in: U256,
+        zero_for_one: bool,
+    ) -> Result<Percent, Error> {
+        let (input_token, output_token, mid_price) = if zero_for_one {
+            (&self.token0, &self.token1, self.token0_price())
+        } else {
+            (&self.token1, &self.token0, self.token1_price())
+        };
+        let spot_output_amount = mid_price.quote(&CurrencyAmount::from_raw_amount(
+            input_token.clone(),
+            amount_in.to_big_int(),
+        )?)?;
+        let output_amount = CurrencyAmount::from_raw_amount(
+            output_token.clone(),
+            self.amount_out_within_tick(amount_in, zero_for_one)?
+                .to_big_int(),
+        )?;
+        let price_impact = spot_output_amount
+            .subtract(&output_amount)?
+            .divide(&spot_output_amount)?;
+        Ok(Percent::new(
+            price_impact.numerator,
+            price_impact.denominator,
+        ))
+    }
+
+    /// Returns the input amount, including the fee, required to push the price of the pool exactly
+    /// to the given tick. The direction of the swap is implied by the side of the current price the
+    /// target tick is on
+    ///
+    /// ## Arguments
+    ///
+    /// * `target_tick`: The tick to move the price of the pool to
+    ///
+    /// returns: The input amount in token0 if the target is below the current price, or in token1
+    /// if it is above
+    #[inline]
+    pub fn amount_in_to_tick(&self, target_tick: i32) -> Result<CurrencyAmount<Token>, Error> {
+        let tick =
+            I24::try_from(target_tick).unwrap_or(if target_tick < 0 { I24::MIN } else { I24::MAX });
+        let sqrt_price_target_x96 = get_sqrt_ratio_at_tick(tick)?;
+        let zero_for_one = sqrt_price_target_x96 < self.sqrt_ratio_x96;
+        let target_is_valid = if zero_for_one {
+            sqrt_price_target_x96 > MIN_SQRT_RATIO
+        } else {
+            sqrt_price_target_x96 > self.sqrt_ratio_x96 && sqrt_price_target_x96 < MAX_SQRT_RATIO
+        };
+        if !target_is_valid {
+            return Err(Error::InvalidTick(tick));
         }
 
-        let output_token = if zero_for_one {
+        let SwapState {
+            amount_specified_remaining,
+            sqrt_price_x96,
+            ..
+        } = self._swap(zero_for_one, I256::MAX, Some(sqrt_price_target_x96))?;
+        debug_assert_eq!(sqrt_price_x96, sqrt_price_target_x96);
+
+        let input_token = if zero_for_one {
+            &self.token0
+        } else {
             &self.token1
+        };
+        CurrencyAmount::from_raw_amount(
+            input_token.clone(),
+            (I256::MAX - amount_specified_remaining).to_big_int(),
+        )
+        .map_err(Error::Core)
+    }
+
+    /// Given an input amount of a token, return the mid price of the pool in terms of token0 after
+    /// the swap, without updating the pool state
+    ///
+    /// ## Arguments
+    ///
+    /// * `amount_in`: The input amount to simulate the swap with
+    ///
+    /// returns: The token0 price after the swap
+    #[inline]
+    pub fn price_after_swap(
+        &self,
+        amount_in: &CurrencyAmount<impl BaseCurrency>,
+    ) -> Result<Price<Token, Token>, Error> {
+        let (_, state) =
+            self.simulate_swap(amount_in, true, None, &self.tick_data_provider, None, None)?;
+
+        let sqrt_price_x96 = state.sqrt_price_x96.to_big_int();
+        Ok(Price::new(
+            self.token0.clone(),
+            self.token1.clone(),
+            Q192_BIG_INT,
+            sqrt_price_x96 * sqrt_price_x96,
+        ))
+    }
+
+    /// Returns the sqrt price limit at which the price of the pool would have moved by
+    /// `max_impact` from the current price in the direction of the swap, clamped to the valid
+    /// range of sqrt ratios
+    ///
+    /// ## Arguments
+    ///
+    /// * `zero_for_one`: Whether the swap is token0 for token1, which moves the price down
+    /// * `max_impact`: The maximum relative price movement
+    ///
+    /// returns: The Q64.96 sqrt price limit to pass to the swap
+    #[inline]
+    pub fn price_limit_for_impact(
+        &self,
+        zero_for_one: bool,
+        max_impact: Percent,
+    ) -> Result<U160, Error> {
+        let impact = max_impact.as_fraction();
+        let numerator = if zero_for_one {
+            (impact.denominator - impact.numerator).max(BigInt::ZERO)
         } else {
-            &self.token0
+            impact.denominator + impact.numerator
         };
-        CurrencyAmount::from_raw_amount(output_token.clone(), -output_amount.to_big_int())
-            .map_err(Error::Core)
+        let sqrt_ratio_x96 = self.sqrt_ratio_x96.to_big_int();
+        let limit = sqrt(sqrt_ratio_x96 * sqrt_ratio_x96 * numerator / impact.denominator)?;
+        let min = (MIN_SQRT_RATIO + ONE).to_big_int();
+        let max = (MAX_SQRT_RATIO - ONE).to_big_int();
+        Ok(U160::from_big_int(limit.clamp(min, max)))
     }
 
     /// Given an input amount of a token, return the computed output amount, updating the pool state
@@ -306,38 +968,19 @@ impl<TP: TickDataProvider> Pool<TP> {
         input_amount: &CurrencyAmount<impl BaseCurrency>,
         sqrt_price_limit_x96: Option<U160>,
     ) -> Result<CurrencyAmount<Token>, Error> {
-        if !self.involves_token(&input_amount.currency) {
-            return Err(Error::InvalidToken);
-        }
-
-        let zero_for_one = input_amount.currency.equals(&self.token0);
-
-        let SwapState {
-            amount_specified_remaining,
-            amount_calculated: output_amount,
-            sqrt_price_x96,
-            liquidity,
-            ..
-        } = self._swap(
-            zero_for_one,
-            I256::from_big_int(input_amount.quotient()),
+        let (zero_for_one, state) = self.simulate_swap(
+            input_amount,
+            true,
             sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            None,
         )?;
+        let (_, output_token) = self.swap_tokens(zero_for_one);
+        let output_token = output_token.clone();
 
-        if !amount_specified_remaining.is_zero() && sqrt_price_limit_x96.is_none() {
-            return Err(Error::InsufficientLiquidity);
-        }
-
-        let output_token = if zero_for_one {
-            &self.token1
-        } else {
-            &self.token0
-        };
-
-        self.sqrt_ratio_x96 = sqrt_price_x96;
-        self.tick_current = TP::Index::from_i24(sqrt_price_x96.get_tick_at_sqrt_ratio()?);
-        self.liquidity = liquidity;
-        CurrencyAmount::from_raw_amount(output_token.clone(), -output_amount.to_big_int())
+        self.apply_swap_state(&state);
+        CurrencyAmount::from_raw_amount(output_token, -state.amount_calculated.to_big_int())
             .map_err(Error::Core)
     }
 
@@ -357,33 +1000,92 @@ impl<TP: TickDataProvider> Pool<TP> {
         output_amount: &CurrencyAmount<impl BaseCurrency>,
         sqrt_price_limit_x96: Option<U160>,
     ) -> Result<CurrencyAmount<Token>, Error> {
-        if !self.involves_token(&output_amount.currency) {
-            return Err(Error::InvalidToken);
-        }
-
-        let zero_for_one = output_amount.currency.equals(&self.token1);
-
-        let SwapState {
-            amount_specified_remaining,
-            amount_calculated: input_amount,
-            ..
-        } = self._swap(
-            zero_for_one,
-            I256::from_big_int(-output_amount.quotient()),
+        let (zero_for_one, state) = self.simulate_swap(
+            output_amount,
+            false,
             sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            None,
         )?;
+        let (input_token, _) = self.swap_tokens(zero_for_one);
+        CurrencyAmount::from_raw_amount(input_token.clone(), state.amount_calculated.to_big_int())
+            .map_err(Error::Core)
+    }
 
-        if !amount_specified_remaining.is_zero() && sqrt_price_limit_x96.is_none() {
-            return Err(Error::InsufficientLiquidity);
-        }
+    /// Given a desired output amount of a token, return the computed input amount and every step
+    /// of the swap loop
+    ///
+    /// ## Arguments
+    ///
+    /// * `output_amount`: the output amount for which to quote the input amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit
+    ///
+    /// returns: The input amount and the swap steps. If the swap is halted by
+    /// `sqrt_price_limit_x96`, the input amount only buys part of the output and the output
+    /// actually delivered is not returned, see [`Pool::get_input_amount_with_limit`] to get it
+    #[inline]
+    pub fn get_input_amount_with_trace(
+        &self,
+        output_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: Option<U160>,
+    ) -> Result<(CurrencyAmount<Token>, Vec<SwapStep<TP::Index>>), Error> {
+        let mut steps = Vec::new();
+        let (zero_for_one, state) = self.simulate_swap(
+            output_amount,
+            false,
+            sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            Some(&mut steps),
+        )?;
+        let (input_token, _) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                input_token.clone(),
+                state.amount_calculated.to_big_int(),
+            )?,
+            steps,
+        ))
+    }
 
-        let input_token = if zero_for_one {
-            &self.token0
-        } else {
-            &self.token1
-        };
-        CurrencyAmount::from_raw_amount(input_token.clone(), input_amount.to_big_int())
-            .map_err(Error::Core)
+    /// Given a desired output amount of a token and a price limit, return the computed input amount
+    /// and the output amount actually delivered before the limit is reached
+    ///
+    /// ## Arguments
+    ///
+    /// * `output_amount`: the output amount for which to quote the input amount
+    /// * `sqrt_price_limit_x96`: The Q64.96 sqrt price limit. If zero for one, the price cannot be
+    ///   less than this value after the swap. If one for zero, the price cannot be greater than
+    ///   this value after the swap
+    ///
+    /// returns: The input amount and the delivered output amount, or
+    /// [`Error::InvalidSqrtPrice`] if the limit is on the wrong side of the current price
+    #[inline]
+    pub fn get_input_amount_with_limit(
+        &self,
+        output_amount: &CurrencyAmount<impl BaseCurrency>,
+        sqrt_price_limit_x96: U160,
+    ) -> Result<(CurrencyAmount<Token>, CurrencyAmount<Token>), Error> {
+        let (zero_for_one, state) = self.simulate_swap(
+            output_amount,
+            false,
+            Some(sqrt_price_limit_x96),
+            &self.tick_data_provider,
+            None,
+            None,
+        )?;
+        let (input_token, output_token) = self.swap_tokens(zero_for_one);
+        Ok((
+            CurrencyAmount::from_raw_amount(
+                input_token.clone(),
+                state.amount_calculated.to_big_int(),
+            )?,
+            CurrencyAmount::from_raw_amount(
+                output_token.clone(),
+                output_amount.quotient() + state.amount_specified_remaining.to_big_int(),
+            )?,
+        ))
     }
 
     /// Given a desired output amount of a token, return the computed input amount, updating the
@@ -403,39 +1105,229 @@ impl<TP: TickDataProvider> Pool<TP> {
         output_amount: &CurrencyAmount<impl BaseCurrency>,
         sqrt_price_limit_x96: Option<U160>,
     ) -> Result<CurrencyAmount<Token>, Error> {
-        if !self.involves_token(&output_amount.currency) {
-            return Err(Error::InvalidToken);
+        let (zero_for_one, state) = self.simulate_swap(
+            output_amount,
+            false,
+            sqrt_price_limit_x96,
+            &self.tick_data_provider,
+            None,
+            None,
+        )?;
+        let (input_token, _) = self.swap_tokens(zero_for_one);
+        let input_token = input_token.clone();
+
+        self.apply_swap_state(&state);
+        CurrencyAmount::from_raw_amount(input_token, state.amount_calculated.to_big_int())
+            .map_err(Error::Core)
+    }
+
+    /// Applies the state emitted by a `Swap` event of the pool, keeping an in-memory pool in sync
+    /// with the chain
+    ///
+    /// The tick must be the one the pool contract computes for the sqrt price, which is one below
+    /// the tick of the sqrt price when a swap moving down stops exactly on a tick boundary.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sqrt_price_x96`: The sqrt price of the pool after the swap
+    /// * `liquidity`: The in range liquidity of the pool after the swap
+    /// * `tick`: The tick of the pool after the swap
+    #[inline]
+    pub fn apply_swap(
+        &mut self,
+        sqrt_price_x96: U160,
+        liquidity: u128,
+        tick: i32,
+    ) -> Result<(), Error> {
+        let expected = sqrt_price_x96.get_tick_at_sqrt_ratio()?.as_i32();
+        let at_boundary = get_sqrt_ratio_at_tick(expected.to_i24())? == sqrt_price_x96;
+        if tick != expected && !(at_boundary && tick == expected - 1) {
+            return Err(Error::InvalidTick(
+                I24::try_from(tick).unwrap_or(if tick < 0 { I24::MIN } else { I24::MAX }),
+            ));
         }
+        self.sqrt_ratio_x96 = sqrt_price_x96;
+        self.tick_current = TP::Index::from_i24(tick.to_i24());
+        self.liquidity = liquidity;
+        Ok(())
+    }
+}
 
-        let zero_for_one = output_amount.currency.equals(&self.token1);
+impl Pool<TickListDataProvider> {
+    /// Returns the tick range around the current tick that holds the given fraction of the pool's
+    /// liquidity, walking outward one tick spacing at a time on both sides
+    ///
+    /// ## Arguments
+    ///
+    /// * `fraction`: The fraction of the total liquidity the range should hold
+    ///
+    /// returns: The lower and upper ticks of the range
+    #[inline]
+    pub fn liquidity_concentration_range(&self, fraction: Percent) -> Result<(i32, i32), Error> {
+        assert!(
+            fraction.numerator > BigInt::ZERO && fraction.numerator <= fraction.denominator,
+            "FRACTION"
+        );
+        let ticks: &[Tick] = &self.tick_data_provider;
+        let (Some(first), Some(last)) = (ticks.first(), ticks.last()) else {
+            return Err(Error::InsufficientLiquidity);
+        };
+        let tick_spacing = self.tick_spacing();
+        let liquidity_net = |tick: i32| {
+            ticks
+                .binary_search_by_key(&tick, |t| t.index)
+                .map_or(0, |i| ticks[i].liquidity_net)
+        };
 
-        let SwapState {
-            amount_specified_remaining,
-            amount_calculated: input_amount,
-            sqrt_price_x96,
-            liquidity,
-            ..
-        } = self._swap(
-            zero_for_one,
-            I256::from_big_int(-output_amount.quotient()),
-            sqrt_price_limit_x96,
-        )?;
+        // the liquidity held by each tick spacing wide step, summed over the initialized range
+        let mut total = U256::ZERO;
+        let mut active = 0_u128;
+        for window in ticks.windows(2) {
+            active = add_delta(active, window[0].liquidity_net)?;
+            let steps = (window[1].index - window[0].index) / tick_spacing;
+            total += U256::from(active) * U256::from(steps);
+        }
+        let target = U256::from_big_int(fraction.numerator) * total;
+        let denominator = U256::from_big_int(fraction.denominator);
+
+        let mut lower = self.tick_current.div_euclid(tick_spacing) * tick_spacing;
+        let mut upper = lower + tick_spacing;
+        let mut liquidity_lower = self.liquidity;
+        let mut liquidity_upper = self.liquidity;
+        let mut cumulative = U256::from(self.liquidity);
+        while cumulative * denominator < target {
+            let can_extend_lower = lower > first.index;
+            let can_extend_upper = upper < last.index;
+            if !can_extend_lower && !can_extend_upper {
+                break;
+            }
+            if can_extend_lower {
+                liquidity_lower = add_delta(liquidity_lower, -liquidity_net(lower))?;
+                lower -= tick_spacing;
+                cumulative += U256::from(liquidity_lower);
+            }
+            if can_extend_upper {
+                liquidity_upper = add_delta(liquidity_upper, liquidity_net(upper))?;
+                upper += tick_spacing;
+                cumulative += U256::from(liquidity_upper);
+            }
+        }
+        Ok((lower, upper))
+    }
+}
+
+/// With the `serde` feature, a pool is serialized through its tokens, fee, price, liquidity and
+/// tick data provider. The current tick is recomputed from the price on deserialization, and the
+/// ticks of the provider are checked against the tick spacing of the fee.
+#[cfg(feature = "serde")]
+mod pool_serde {
+    use super::*;
+    use alloc::string::String;
+    use alloy_primitives::aliases::U24;
+    use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct TokenRepr {
+        chain_id: ChainId,
+        address: Address,
+        decimals: u8,
+        symbol: Option<String>,
+        name: Option<String>,
+        #[serde(default)]
+        buy_fee_bps: Option<U256>,
+        #[serde(default)]
+        sell_fee_bps: Option<U256>,
+    }
+
+    impl From<&Token> for TokenRepr {
+        #[inline]
+        fn from(token: &Token) -> Self {
+            Self {
+                chain_id: token.chain_id(),
+                address: token.address(),
+                decimals: token.decimals(),
+                symbol: token.symbol().cloned(),
+                name: token.name().cloned(),
+                buy_fee_bps: token.buy_fee_bps.map(U256::from_big_int),
+                sell_fee_bps: token.sell_fee_bps.map(U256::from_big_int),
+            }
+        }
+    }
+
+    impl From<TokenRepr> for Token {
+        #[inline]
+        fn from(token: TokenRepr) -> Self {
+            Self::new(
+                token.chain_id,
+                token.address,
+                token.decimals,
+                token.symbol,
+                token.name,
+                token.buy_fee_bps.map(|bps| bps.to_big_int()),
+                token.sell_fee_bps.map(|bps| bps.to_big_int()),
+            )
+        }
+    }
+
+    #[derive(Serialize)]
+    struct PoolRef<'a, TP> {
+        token0: TokenRepr,
+        token1: TokenRepr,
+        fee: u32,
+        sqrt_ratio_x96: U160,
+        liquidity: u128,
+        tick_data_provider: &'a TP,
+    }
 
-        if !amount_specified_remaining.is_zero() && sqrt_price_limit_x96.is_none() {
-            return Err(Error::InsufficientLiquidity);
-        }
+    #[derive(Deserialize)]
+    struct PoolRepr<TP> {
+        token0: TokenRepr,
+        token1: TokenRepr,
+        fee: u32,
+        sqrt_ratio_x96: U160,
+        liquidity: u128,
+        tick_data_provider: TP,
+    }
 
-        let input_token = if zero_for_one {
-            &self.token0
-        } else {
-            &self.token1
-        };
+    impl<TP> Serialize for Pool<TP>
+    where
+        TP: TickDataProvider + Serialize,
+    {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PoolRef {
+                token0: (&self.token0).into(),
+                token1: (&self.token1).into(),
+                fee: U24::from(self.fee).to(),
+                sqrt_ratio_x96: self.sqrt_ratio_x96,
+                liquidity: self.liquidity,
+                tick_data_provider: &self.tick_data_provider,
+            }
+            .serialize(serializer)
+        }
+    }
 
-        self.sqrt_ratio_x96 = sqrt_price_x96;
-        self.tick_current = TP::Index::from_i24(sqrt_price_x96.get_tick_at_sqrt_ratio()?);
-        self.liquidity = liquidity;
-        CurrencyAmount::from_raw_amount(input_token.clone(), input_amount.to_big_int())
-            .map_err(Error::Core)
+    impl<'de, TP> Deserialize<'de> for Pool<TP>
+    where
+        TP: TickDataProvider + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let pool = PoolRepr::<TP>::deserialize(deserializer)?;
+            let pool = Self::new_with_tick_data_provider(
+                pool.token0.into(),
+                pool.token1.into(),
+                pool.fee.into(),
+                pool.sqrt_ratio_x96,
+                pool.liquidity,
+                pool.tick_data_provider,
+            )
+            .map_err(D::Error::custom)?;
+            pool.tick_data_provider
+                .validate_tick_spacing(pool.tick_spacing())
+                .map_err(D::Error::custom)?;
+            Ok(pool)
+        }
     }
 }
 
@@ -443,7 +1335,8 @@ impl<TP: TickDataProvider> Pool<TP> {
 mod tests {
     use super::*;
     use crate::tests::*;
-    use alloy_primitives::address;
+    use alloy_primitives::{address, aliases::U24, keccak256, U256};
+    use alloy_sol_types::SolValue;
 
     const ONE_ETHER: U160 = U160::from_limbs([10_u64.pow(18), 0, 0]);
 
@@ -487,6 +1380,21 @@ mod tests {
             let weth9 = WETH9::default().get(1).unwrap().clone();
             Pool::new(USDC.clone(), weth9, FeeAmount::HIGH, ONE_ETHER, 0).unwrap();
         }
+
+        #[test]
+        fn rejects_sqrt_ratio_out_of_bounds() {
+            use alloy_primitives::ruint::UintTryFrom;
+
+            // a 161-bit ratio cannot be narrowed to the `U160` the constructor takes
+            assert!(U160::uint_try_from(U256::from(1) << 160).is_err());
+            let weth9 = WETH9::default().get(1).unwrap().clone();
+            for sqrt_ratio_x96 in [MIN_SQRT_RATIO - U160::from(1), MAX_SQRT_RATIO] {
+                assert!(matches!(
+                    Pool::new(USDC.clone(), weth9.clone(), FeeAmount::MEDIUM, sqrt_ratio_x96, 0),
+                    Err(Error::InvalidSqrtPrice(price)) if price == sqrt_ratio_x96
+                ));
+            }
+        }
     }
 
     #[test]
@@ -495,6 +1403,164 @@ mod tests {
         assert_eq!(result, address!("6c6Bc977E13Df9b0de53b251522280BB72383700"));
     }
 
+    #[test]
+    fn address_uses_the_raw_fee_for_custom_fee_tiers() {
+        assert_eq!(
+            Pool::get_address(&USDC, &DAI, FeeAmount::CUSTOM(500), None, None),
+            address!("6c6Bc977E13Df9b0de53b251522280BB72383700")
+        );
+
+        let pool = Pool::new(
+            USDC.clone(),
+            DAI.clone(),
+            FeeAmount::CUSTOM(2500),
+            encode_sqrt_ratio_x96(1, 1),
+            0,
+        )
+        .unwrap();
+        let salt = keccak256((DAI.address(), USDC.address(), U24::from_limbs([2500])).abi_encode());
+        assert_eq!(
+            pool.address(None, None),
+            FACTORY_ADDRESS.create2(salt, POOL_INIT_CODE_HASH)
+        );
+    }
+
+    #[test]
+    fn initial_sqrt_ratio_from_amounts_encodes_the_price() {
+        let sqrt_ratio_x96 =
+            Pool::initial_sqrt_ratio_from_amounts(U256::from(1), U256::from(3000)).unwrap();
+        let expected: U160 = encode_sqrt_ratio_x96(3000, 1);
+        assert_eq!(sqrt_ratio_x96, expected);
+        assert_eq!(
+            sqrt_ratio_x96.get_tick_at_sqrt_ratio().unwrap().as_i32(),
+            80067
+        );
+        assert!(matches!(
+            Pool::initial_sqrt_ratio_from_amounts(U256::ZERO, U256::from(3000)),
+            Err(Error::ZeroAmount)
+        ));
+        assert!(matches!(
+            Pool::initial_sqrt_ratio_from_amounts(U256::from(1), U256::ZERO),
+            Err(Error::ZeroAmount)
+        ));
+        assert!(matches!(
+            Pool::initial_sqrt_ratio_from_amounts(U256::from(1), U256::MAX),
+            Err(Error::InvalidSqrtPrice(_))
+        ));
+    }
+
+    #[test]
+    fn liquidity_concentration_range_walks_outward_from_the_current_tick() {
+        let pool = Pool::new_with_tick_data_provider(
+            TOKEN0.clone(),
+            TOKEN1.clone(),
+            FeeAmount::MEDIUM,
+            encode_sqrt_ratio_x96(1, 1),
+            30,
+            TickListDataProvider::new(
+                vec![
+                    Tick::new(-120, 10, 10),
+                    Tick::new(-60, 20, 20),
+                    Tick::new(60, 20, -20),
+                    Tick::new(120, 10, -10),
+                ],
+                60,
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            pool.liquidity_concentration_range(Percent::new(25, 100))
+                .unwrap(),
+            (0, 60)
+        );
+        assert_eq!(
+            pool.liquidity_concentration_range(Percent::new(50, 100))
+                .unwrap(),
+            (-60, 120)
+        );
+        assert_eq!(
+            pool.liquidity_concentration_range(Percent::new(90, 100))
+                .unwrap(),
+            (-120, 120)
+        );
+    }
+
+    #[test]
+    fn reserves_are_in_terms_of_the_pool_tokens() {
+        let pool = make_pool(TOKEN1.clone(), TOKEN0.clone());
+        let (reserve0, reserve1) = pool.reserves().unwrap();
+        assert!(reserve0.currency.equals(&TOKEN0.clone()));
+        assert!(reserve1.currency.equals(&TOKEN1.clone()));
+        assert_eq!(reserve0.quotient(), U256::from(LIQUIDITY).to_big_int());
+        assert_eq!(reserve1.quotient(), U256::from(LIQUIDITY).to_big_int());
+    }
+
+    #[test]
+    fn tick_for_price_snaps_down_and_up() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        let price = tick_to_price(TOKEN0.clone(), TOKEN1.clone(), I24::from_limbs([100])).unwrap();
+        assert_eq!(
+            pool.tick_for_price(&price, Rounding::RoundDown).unwrap(),
+            60
+        );
+        assert_eq!(pool.tick_for_price(&price, Rounding::RoundUp).unwrap(), 120);
+        assert_eq!(
+            pool.tick_for_price(&price, Rounding::RoundHalfUp).unwrap(),
+            120
+        );
+        let price = tick_to_price(TOKEN0.clone(), TOKEN1.clone(), -I24::from_limbs([100])).unwrap();
+        assert_eq!(
+            pool.tick_for_price(&price, Rounding::RoundDown).unwrap(),
+            -120
+        );
+        assert_eq!(pool.tick_for_price(&price, Rounding::RoundUp).unwrap(), -60);
+    }
+
+    #[test]
+    fn full_range_amounts_fills_in_amount1() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        let (amount0, amount1, liquidity) = pool
+            .full_range_amounts(Some(U256::from(1000)), None)
+            .unwrap();
+        assert!(amount0 <= U256::from(1000));
+        // the range is symmetric around the price of 1
+        assert!(amount1.abs_diff(amount0) <= U256::from(1));
+        let tick_spacing = pool.tick_spacing();
+        let position = Position::new(
+            pool,
+            liquidity,
+            nearest_usable_tick(MIN_TICK_I32, tick_spacing),
+            nearest_usable_tick(MAX_TICK_I32, tick_spacing),
+        );
+        let MintAmounts {
+            amount0: mint_amount0,
+            amount1: mint_amount1,
+        } = position.mint_amounts().unwrap();
+        assert_eq!((mint_amount0, mint_amount1), (amount0, amount1));
+    }
+
+    #[test]
+    fn full_range_amounts_requires_exactly_one_amount() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        assert!(matches!(
+            pool.full_range_amounts(Some(U256::from(1000)), Some(U256::from(1000))),
+            Err(Error::InvalidAmounts)
+        ));
+        assert!(matches!(
+            pool.full_range_amounts(None, None),
+            Err(Error::InvalidAmounts)
+        ));
+    }
+
+    #[test]
+    fn full_range_amounts_fails_if_liquidity_overflows() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        assert!(matches!(
+            pool.full_range_amounts(Some(U256::from(u128::MAX) << 64), None),
+            Err(Error::SafeCastToU128Overflow)
+        ));
+    }
+
     #[test]
     fn token0_always_is_the_token_that_sorts_before() {
         let pool = Pool::new(
@@ -589,6 +1655,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prices_at_ticks_matches_tick_to_price() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        let ticks = [-60, 0, 60, MAX_TICK_I32];
+        let prices = pool.prices_at_ticks(&ticks).unwrap();
+        assert_eq!(prices.len(), ticks.len());
+        for (price, tick) in prices.iter().zip(ticks) {
+            assert_eq!(
+                *price,
+                tick_to_price(TOKEN0.clone(), TOKEN1.clone(), tick.to_i24()).unwrap()
+            );
+        }
+        assert_eq!(prices[1].to_significant(5, None).unwrap(), "1");
+    }
+
+    #[test]
+    fn prices_at_ticks_errors_for_ticks_out_of_range() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        assert!(matches!(
+            pool.prices_at_ticks(&[0, MAX_TICK_I32 + 1]),
+            Err(Error::InvalidTick(tick)) if tick == (MAX_TICK_I32 + 1).to_i24()
+        ));
+        assert!(pool.prices_at_ticks(&[i32::MIN]).is_err());
+    }
+
     #[test]
     fn price_of_returns_price_of_token_in_terms_of_other_token() {
         let pool = Pool::new(
@@ -655,6 +1746,17 @@ mod tests {
         assert!(!pool.involves_token(&WETH9::default().get(1).unwrap().clone()));
     }
 
+    #[test]
+    fn other_token() {
+        assert_eq!(POOL_0_1.other_token(&TOKEN0.clone()), Some(&*TOKEN1));
+        assert_eq!(POOL_0_1.other_token(&TOKEN1.clone()), Some(&*TOKEN0));
+        assert_eq!(POOL_0_1.other_token(&TOKEN2.clone()), None);
+        assert_eq!(POOL_0_1.other_token(&ETHER.clone()), None);
+        // native ether is matched by its wrapped token
+        assert_eq!(POOL_0_WETH.other_token(&ETHER.clone()), Some(&*TOKEN0));
+        assert_eq!(POOL_0_WETH.other_token(&TOKEN0.clone()), Some(&*WETH));
+    }
+
     mod swaps {
         use super::*;
         use crate::utils::tick_math::{MAX_TICK, MIN_TICK};
@@ -710,6 +1812,454 @@ mod tests {
             assert_eq!(output_amount.quotient(), 98.into());
         }
 
+        #[test]
+        fn get_output_amount_with_fee_within_a_tick() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1_000_000).unwrap();
+            let (output_amount, fee_amount) = pool
+                .get_output_amount_with_fee(&input_amount, None)
+                .unwrap();
+            assert_eq!(
+                output_amount,
+                pool.get_output_amount(&input_amount, None).unwrap()
+            );
+            assert!(fee_amount.currency.equals(&TOKEN0.clone()));
+            // amount_in * fee_pips / 1e6
+            assert_eq!(fee_amount.quotient(), 3000.into());
+        }
+
+        #[test]
+        fn get_output_amount_with_provider_matches_built_in_provider() {
+            let provider = TickListDataProvider::new(
+                vec![
+                    Tick::new(
+                        nearest_usable_tick(MIN_TICK, FeeAmount::LOW.tick_spacing()).as_i32(),
+                        ONE_ETHER.into_limbs()[0] as u128,
+                        ONE_ETHER.into_limbs()[0] as i128,
+                    ),
+                    Tick::new(
+                        nearest_usable_tick(MAX_TICK, FeeAmount::LOW.tick_spacing()).as_i32(),
+                        ONE_ETHER.into_limbs()[0] as u128,
+                        -(ONE_ETHER.into_limbs()[0] as i128),
+                    ),
+                ],
+                FeeAmount::LOW.tick_spacing().as_i32(),
+            );
+            let input_amount = CurrencyAmount::from_raw_amount(USDC.clone(), 100).unwrap();
+            assert_eq!(
+                POOL.get_output_amount_with_provider(&input_amount, None, &provider)
+                    .unwrap(),
+                POOL.get_output_amount(&input_amount, None).unwrap()
+            );
+        }
+
+        #[test]
+        fn get_output_amount_mut_crosses_tick_reached_exactly() {
+            let tick_spacing = FEE_AMOUNT.tick_spacing().as_i32();
+            let mut pool = Pool::new_with_tick_data_provider(
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+                FEE_AMOUNT,
+                SQRT_RATIO_X96,
+                2 * LIQUIDITY,
+                TickListDataProvider::new(
+                    vec![
+                        Tick::new(
+                            nearest_usable_tick(MIN_TICK, FEE_AMOUNT.tick_spacing()).as_i32(),
+                            LIQUIDITY,
+                            LIQUIDITY as i128,
+                        ),
+                        Tick::new(-tick_spacing, LIQUIDITY, LIQUIDITY as i128),
+                        Tick::new(tick_spacing, LIQUIDITY, -(LIQUIDITY as i128)),
+                        Tick::new(
+                            nearest_usable_tick(MAX_TICK, FEE_AMOUNT.tick_spacing()).as_i32(),
+                            LIQUIDITY,
+                            -(LIQUIDITY as i128),
+                        ),
+                    ],
+                    tick_spacing,
+                ),
+            )
+            .unwrap();
+            // the gross input that moves the price exactly onto the initialized tick
+            let amount_in = get_amount_0_delta(
+                get_sqrt_ratio_at_tick((-tick_spacing).to_i24()).unwrap(),
+                SQRT_RATIO_X96,
+                2 * LIQUIDITY,
+                true,
+            )
+            .unwrap();
+            let amount_in =
+                mul_div_rounding_up(amount_in, U256::from(1_000_000), U256::from(997_000)).unwrap();
+
+            pool.get_output_amount_mut(
+                &CurrencyAmount::from_raw_amount(TOKEN0.clone(), amount_in.to_big_int()).unwrap(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(pool.tick_current, -tick_spacing - 1);
+            assert_eq!(pool.liquidity, LIQUIDITY);
+
+            // swapping further must not cross the same tick a second time
+            pool.get_output_amount_mut(
+                &CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(pool.liquidity, LIQUIDITY);
+        }
+
+        #[test]
+        fn price_after_swap_barely_moves_for_tiny_swap() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let price = pool
+                .price_after_swap(&CurrencyAmount::from_raw_amount(TOKEN1.clone(), 10).unwrap())
+                .unwrap();
+            assert!(price.as_fraction() > pool.token0_price().as_fraction());
+            assert_eq!(price.to_significant(5, None).unwrap(), "1");
+        }
+
+        #[test]
+        fn price_after_swap_moves_materially_for_large_swap() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let price = pool
+                .price_after_swap(&CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100000).unwrap())
+                .unwrap();
+            assert_eq!(price.to_significant(2, None).unwrap(), "1.2");
+            let price = pool
+                .price_after_swap(&CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100000).unwrap())
+                .unwrap();
+            assert_eq!(price.to_significant(2, None).unwrap(), "0.83");
+        }
+
+        const L: u128 = 1_000_000_000_000_000_000;
+
+        fn pool_with_ticks_around_zero() -> (Pool<TickListDataProvider>, Vec<Tick>) {
+            let tick_spacing = FeeAmount::MEDIUM.tick_spacing().as_i32();
+            let ticks = vec![
+                Tick::new(
+                    nearest_usable_tick(MIN_TICK_I32, tick_spacing),
+                    L,
+                    L as i128,
+                ),
+                Tick::new(-120, L, L as i128),
+                Tick::new(-60, L, L as i128),
+                Tick::new(60, L, -(L as i128)),
+                Tick::new(120, L, -(L as i128)),
+                Tick::new(
+                    nearest_usable_tick(MAX_TICK_I32, tick_spacing),
+                    L,
+                    -(L as i128),
+                ),
+            ];
+            let pool = Pool::new_with_tick_data_provider(
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+                FeeAmount::MEDIUM,
+                SQRT_RATIO_X96,
+                3 * L,
+                TickListDataProvider::new(ticks.clone(), tick_spacing),
+            )
+            .unwrap();
+            (pool, ticks)
+        }
+
+        #[test]
+        fn amount_in_to_tick_reaches_the_target_tick() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            for (target_tick, zero_for_one) in [(-60, true), (-90, true), (90, false)] {
+                let amount_in = pool.amount_in_to_tick(target_tick).unwrap();
+                assert_eq!(amount_in.currency.equals(&pool.token0), zero_for_one);
+                let state = pool
+                    ._swap(zero_for_one, I256::from_big_int(amount_in.quotient()), None)
+                    .unwrap();
+                assert_eq!(
+                    state.sqrt_price_x96,
+                    get_sqrt_ratio_at_tick(target_tick.to_i24()).unwrap()
+                );
+                assert!(state.amount_specified_remaining.is_zero());
+            }
+        }
+
+        #[test]
+        fn amount_in_to_tick_rejects_the_current_tick() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            assert!(matches!(
+                pool.amount_in_to_tick(0),
+                Err(Error::InvalidTick(_))
+            ));
+            assert!(matches!(
+                pool.amount_in_to_tick(MIN_TICK_I32),
+                Err(Error::InvalidTick(_))
+            ));
+        }
+
+        #[test]
+        fn get_output_amount_detailed_counts_initialized_ticks_crossed() {
+            let (pool, ticks) = pool_with_ticks_around_zero();
+            let input_amount =
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 20_000_000_000_000_000_u64)
+                    .unwrap();
+            let (output_amount, initialized_ticks_crossed) = pool
+                .get_output_amount_detailed(&input_amount, None)
+                .unwrap();
+            assert_eq!(
+                output_amount,
+                pool.get_output_amount(&input_amount, None).unwrap()
+            );
+
+            // walk the initialized ticks between the price before and after the swap
+            let mut pool_after = pool.clone();
+            pool_after
+                .get_output_amount_mut(&input_amount, None)
+                .unwrap();
+            let expected = ticks
+                .iter()
+                .filter(|tick| {
+                    tick.index > pool_after.tick_current && tick.index <= pool.tick_current
+                })
+                .count();
+            assert_eq!(initialized_ticks_crossed as usize, expected);
+            assert_eq!(initialized_ticks_crossed, 2);
+        }
+
+        #[test]
+        fn amount_out_within_tick_approximates_small_swaps() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            let full_output = |amount: u64| {
+                let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), amount).unwrap();
+                U256::from_big_int(
+                    pool.get_output_amount(&input_amount, None)
+                        .unwrap()
+                        .quotient(),
+                )
+            };
+
+            let small = 1_000_000_000_000_u64;
+            assert_eq!(
+                pool.amount_out_within_tick(U256::from(small), true)
+                    .unwrap(),
+                full_output(small)
+            );
+
+            let large = 20_000_000_000_000_000_u64;
+            assert_ne!(
+                pool.amount_out_within_tick(U256::from(large), true)
+                    .unwrap(),
+                full_output(large)
+            );
+        }
+
+        #[test]
+        fn amount_out_within_tick_rejects_amounts_beyond_int256() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            assert!(matches!(
+                pool.amount_out_within_tick(U256::from(1) << 255, true),
+                Err(Error::InvalidAmounts)
+            ));
+        }
+
+        #[test]
+        fn approximate_price_impact_matches_trade_for_a_small_swap() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            let amount_in = 1_000_000_000_000_u64;
+            let trade = Trade::exact_in(
+                Route::new(vec![pool.clone()], TOKEN0.clone(), TOKEN1.clone()),
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), amount_in).unwrap(),
+            )
+            .unwrap();
+            let price_impact = pool
+                .approximate_price_impact(U256::from(amount_in), true)
+                .unwrap();
+            assert_eq!(price_impact, trade.price_impact().unwrap());
+            assert!(price_impact > Percent::new(0, 1));
+        }
+
+        #[test]
+        fn approximate_price_impact_matches_trade_across_fee_tiers() {
+            for fee in [
+                FeeAmount::LOWEST,
+                FeeAmount::LOW,
+                FeeAmount::MEDIUM,
+                FeeAmount::HIGH,
+            ] {
+                let tick_spacing = fee.tick_spacing().as_i32();
+                let pool = Pool::new_with_tick_data_provider(
+                    TOKEN0.clone(),
+                    TOKEN1.clone(),
+                    fee,
+                    SQRT_RATIO_X96,
+                    L,
+                    TickListDataProvider::new(
+                        vec![
+                            Tick::new(
+                                nearest_usable_tick(MIN_TICK_I32, tick_spacing),
+                                L,
+                                L as i128,
+                            ),
+                            Tick::new(
+                                nearest_usable_tick(MAX_TICK_I32, tick_spacing),
+                                L,
+                                -(L as i128),
+                            ),
+                        ],
+                        tick_spacing,
+                    ),
+                )
+                .unwrap();
+                let amount_in = 1_000_000_000_000_u64;
+                let fee_percent = Percent::new(U24::from(fee).to::<u32>(), 1_000_000);
+                for (input_token, output_token, zero_for_one) in [
+                    (TOKEN0.clone(), TOKEN1.clone(), true),
+                    (TOKEN1.clone(), TOKEN0.clone(), false),
+                ] {
+                    let trade = Trade::exact_in(
+                        Route::new(vec![pool.clone()], input_token.clone(), output_token),
+                        CurrencyAmount::from_raw_amount(input_token, amount_in).unwrap(),
+                    )
+                    .unwrap();
+                    let price_impact = pool
+                        .approximate_price_impact(U256::from(amount_in), zero_for_one)
+                        .unwrap();
+                    assert_eq!(price_impact, trade.price_impact().unwrap());
+                    assert!(price_impact >= fee_percent);
+                }
+            }
+        }
+
+        #[test]
+        fn swap_traces_sum_to_the_single_shot_result() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            let input_amount =
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 20_000_000_000_000_000_u64)
+                    .unwrap();
+            let (output_amount, steps) = pool
+                .get_output_amount_with_trace(&input_amount, None)
+                .unwrap();
+            assert_eq!(
+                output_amount,
+                pool.get_output_amount(&input_amount, None).unwrap()
+            );
+            assert!(steps.len() > 1);
+            assert_eq!(steps[0].sqrt_price_start_x96, pool.sqrt_ratio_x96);
+            assert_eq!(steps[0].liquidity, pool.liquidity);
+            let amount_out: U256 = steps.iter().map(|step| step.amount_out).sum();
+            assert_eq!(amount_out.to_big_int(), output_amount.quotient());
+
+            let (input_amount, steps) = pool
+                .get_input_amount_with_trace(&output_amount, None)
+                .unwrap();
+            assert_eq!(
+                input_amount,
+                pool.get_input_amount(&output_amount, None).unwrap()
+            );
+            let amount_in: U256 = steps
+                .iter()
+                .map(|step| step.amount_in + step.fee_amount)
+                .sum();
+            assert_eq!(amount_in.to_big_int(), input_amount.quotient());
+        }
+
+        #[test]
+        fn get_output_amount_bounded_truncates_a_long_swap() {
+            let (pool, _) = pool_with_ticks_around_zero();
+            let input_amount =
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 20_000_000_000_000_000_u64)
+                    .unwrap();
+            let full_output = pool.get_output_amount(&input_amount, None).unwrap();
+
+            let (output_amount, limit_hit) =
+                pool.get_output_amount_bounded(&input_amount, 1).unwrap();
+            assert!(limit_hit);
+            assert!(output_amount.quotient() < full_output.quotient());
+            assert!(output_amount.quotient() > BigInt::ZERO);
+
+            let (output_amount, limit_hit) =
+                pool.get_output_amount_bounded(&input_amount, 2).unwrap();
+            assert!(!limit_hit);
+            assert_eq!(output_amount, full_output);
+        }
+
+        #[test]
+        fn get_output_amount_bounded_reports_running_out_of_liquidity() {
+            // the only liquidity is between -60 and 60, so the swap crosses exactly one initialized
+            // tick before running dry
+            let pool = Pool::new_with_tick_data_provider(
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+                FeeAmount::MEDIUM,
+                SQRT_RATIO_X96,
+                L,
+                TickListDataProvider::new(
+                    vec![Tick::new(-60, L, L as i128), Tick::new(60, L, -(L as i128))],
+                    60,
+                ),
+            )
+            .unwrap();
+            let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), L as u64).unwrap();
+            assert!(matches!(
+                pool.get_output_amount_bounded(&input_amount, 1),
+                Err(Error::InsufficientLiquidity)
+            ));
+            let state = pool
+                ._swap(true, I256::from_big_int(input_amount.quotient()), None)
+                .unwrap();
+            assert_eq!(state.initialized_ticks_crossed, 1);
+            assert!(!state.truncated);
+        }
+
+        #[test]
+        fn swapping_a_pool_without_liquidity_errors() {
+            let pool = Pool::new_with_tick_data_provider(
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+                FEE_AMOUNT,
+                SQRT_RATIO_X96,
+                0,
+                TickListDataProvider::default(),
+            )
+            .unwrap();
+            let amount0 = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap();
+            let amount1 = CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100).unwrap();
+            assert!(matches!(
+                pool.get_output_amount(&amount0, None),
+                Err(Error::InsufficientLiquidity)
+            ));
+            assert!(matches!(
+                pool.get_output_amount(&amount1, None),
+                Err(Error::InsufficientLiquidity)
+            ));
+            assert!(matches!(
+                pool.get_input_amount(&amount1, None),
+                Err(Error::InsufficientLiquidity)
+            ));
+        }
+
+        #[test]
+        fn price_limit_for_impact_moves_in_the_swap_direction() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let limit = pool
+                .price_limit_for_impact(true, Percent::new(1, 100))
+                .unwrap();
+            assert!(limit < pool.sqrt_ratio_x96);
+            assert_eq!(limit, encode_sqrt_ratio_x96(99, 100));
+            let limit = pool
+                .price_limit_for_impact(false, Percent::new(1, 100))
+                .unwrap();
+            assert!(limit > pool.sqrt_ratio_x96);
+            assert_eq!(limit, encode_sqrt_ratio_x96(101, 100));
+        }
+
+        #[test]
+        fn price_limit_for_impact_is_clamped_to_valid_sqrt_ratios() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            assert_eq!(
+                pool.price_limit_for_impact(true, Percent::new(1, 1))
+                    .unwrap(),
+                MIN_SQRT_RATIO + ONE
+            );
+        }
+
         #[test]
         fn get_input_amount_usdc_to_dai() {
             let input_amount = POOL
@@ -722,6 +2272,138 @@ mod tests {
             assert_eq!(input_amount.quotient(), 100.into());
         }
 
+        #[test]
+        fn swap_deltas_match_the_swap_event_sign_convention() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+
+            // exact input of token0: token0 paid in, token1 paid out
+            let (amount0, amount1) = pool
+                .swap_deltas(I256::from_raw(U256::from(1000)), true, None)
+                .unwrap();
+            assert_eq!(amount0, I256::from_raw(U256::from(1000)));
+            assert!(amount1.is_negative());
+            let output_amount = pool
+                .get_output_amount(
+                    &CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap(),
+                    None,
+                )
+                .unwrap();
+            assert_eq!(-amount1.to_big_int(), output_amount.quotient());
+
+            // exact output of token0: token1 paid in, token0 paid out
+            let (amount0, amount1) = pool
+                .swap_deltas(-I256::from_raw(U256::from(1000)), false, None)
+                .unwrap();
+            assert_eq!(amount0, -I256::from_raw(U256::from(1000)));
+            assert!(amount1.is_positive());
+        }
+
+        #[test]
+        fn get_output_amount_with_limit_hit_mid_swap() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 200000).unwrap();
+            let (output_amount, remaining) = pool
+                .get_output_amount_with_limit(&input_amount, encode_sqrt_ratio_x96(100, 121))
+                .unwrap();
+            assert!(output_amount.currency.equals(&TOKEN1.clone()));
+            assert!(remaining.currency.equals(&TOKEN0.clone()));
+            assert!(output_amount.quotient() <= 90909.into());
+            assert!(remaining.quotient() > BigInt::ZERO);
+            assert!(remaining.quotient() < input_amount.quotient());
+        }
+
+        #[test]
+        fn get_output_amount_with_limit_never_reached() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100000).unwrap();
+            let (output_amount, remaining) = pool
+                .get_output_amount_with_limit(&input_amount, encode_sqrt_ratio_x96(1, 4))
+                .unwrap();
+            assert_eq!(
+                output_amount,
+                pool.get_output_amount(&input_amount, None).unwrap()
+            );
+            assert_eq!(remaining.quotient(), BigInt::ZERO);
+        }
+
+        #[test]
+        fn get_output_amount_with_limit_on_wrong_side_of_price() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let limit = encode_sqrt_ratio_x96(4, 1);
+            assert!(matches!(
+                pool.get_output_amount_with_limit(
+                    &CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100000).unwrap(),
+                    limit,
+                ),
+                Err(Error::InvalidSqrtPrice(price)) if price == limit
+            ));
+        }
+
+        #[test]
+        fn every_variant_rejects_a_limit_on_the_wrong_side_of_price() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let limit = Some(encode_sqrt_ratio_x96(4, 1));
+            let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100000).unwrap();
+            let output_amount = CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100000).unwrap();
+            assert!(matches!(
+                pool.get_output_amount(&input_amount, limit),
+                Err(Error::InvalidSqrtPrice(_))
+            ));
+            assert!(matches!(
+                pool.get_output_amount_with_trace(&input_amount, limit),
+                Err(Error::InvalidSqrtPrice(_))
+            ));
+            assert!(matches!(
+                pool.get_input_amount(&output_amount, limit),
+                Err(Error::InvalidSqrtPrice(_))
+            ));
+        }
+
+        #[test]
+        fn get_input_amount_with_limit_hit_mid_swap() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let output_amount = CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100000).unwrap();
+            let (input_amount, delivered) = pool
+                .get_input_amount_with_limit(&output_amount, encode_sqrt_ratio_x96(100, 121))
+                .unwrap();
+            assert!(input_amount.currency.equals(&TOKEN0.clone()));
+            assert!(delivered.currency.equals(&TOKEN1.clone()));
+            assert_eq!(delivered.quotient(), 90909.into());
+            assert!(
+                input_amount.quotient()
+                    < pool
+                        .get_input_amount(&output_amount, None)
+                        .unwrap()
+                        .quotient()
+            );
+        }
+
+        #[test]
+        fn get_input_amount_with_limit_never_reached() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            let output_amount = CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100000).unwrap();
+            let (input_amount, delivered) = pool
+                .get_input_amount_with_limit(&output_amount, encode_sqrt_ratio_x96(1, 4))
+                .unwrap();
+            assert_eq!(delivered, output_amount);
+            assert_eq!(
+                input_amount,
+                pool.get_input_amount(&output_amount, None).unwrap()
+            );
+        }
+
+        #[test]
+        fn get_input_amount_with_limit_on_wrong_side_of_price() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            assert!(matches!(
+                pool.get_input_amount_with_limit(
+                    &CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100000).unwrap(),
+                    encode_sqrt_ratio_x96(4, 1),
+                ),
+                Err(Error::InvalidSqrtPrice(_))
+            ));
+        }
+
         #[test]
         fn get_input_amount_dai_to_usdc() {
             let input_amount = POOL
@@ -734,4 +2416,119 @@ mod tests {
             assert_eq!(input_amount.quotient(), 100.into());
         }
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_keeps_token_fees_and_checks_ticks() {
+        let fee_on_transfer = Token::new(
+            1,
+            TOKEN0.address(),
+            TOKEN0.decimals(),
+            TOKEN0.symbol().cloned(),
+            TOKEN0.name().cloned(),
+            Some(BigInt::from(100)),
+            Some(BigInt::from(200)),
+        );
+        let pool = make_pool(fee_on_transfer, TOKEN1.clone());
+        let json = serde_json::to_string(&pool).unwrap();
+        let restored: Pool<TickListDataProvider> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.token0.buy_fee_bps, Some(BigInt::from(100)));
+        assert_eq!(restored.token0.sell_fee_bps, Some(BigInt::from(200)));
+        assert_eq!(restored.token1.buy_fee_bps, None);
+
+        // ticks that are not multiples of the tick spacing of the fee
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["tick_data_provider"] =
+            serde_json::to_value(alloc::vec![Tick::new(-1, 1, 1), Tick::new(1, 1, -1)]).unwrap();
+        assert!(serde_json::from_value::<Pool<TickListDataProvider>>(value).is_err());
+        // unsorted ticks
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["tick_data_provider"] =
+            serde_json::to_value(alloc::vec![Tick::new(60, 1, -1), Tick::new(-60, 1, 1)]).unwrap();
+        assert!(serde_json::from_value::<Pool<TickListDataProvider>>(value).is_err());
+    }
+
+    #[test]
+    fn apply_swap_updates_the_prices() {
+        let mut pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        let sqrt_price_x96 = get_sqrt_ratio_at_tick(I24::from_limbs([100])).unwrap();
+        pool.apply_swap(sqrt_price_x96, LIQUIDITY * 2, 100).unwrap();
+        assert_eq!(pool.sqrt_ratio_x96, sqrt_price_x96);
+        assert_eq!(pool.tick_current, 100);
+        assert_eq!(pool.liquidity, LIQUIDITY * 2);
+        assert_eq!(
+            pool.token0_price(),
+            tick_to_price(TOKEN0.clone(), TOKEN1.clone(), I24::from_limbs([100])).unwrap()
+        );
+
+        // a swap moving down that stops on a tick boundary leaves the pool one tick below
+        pool.apply_swap(sqrt_price_x96, LIQUIDITY, 99).unwrap();
+        assert_eq!(pool.tick_current, 99);
+
+        assert!(matches!(
+            pool.apply_swap(sqrt_price_x96, LIQUIDITY, 101),
+            Err(Error::InvalidTick(_))
+        ));
+        assert!(matches!(
+            pool.apply_swap(sqrt_price_x96 + U160::from(1), LIQUIDITY, 99),
+            Err(Error::InvalidTick(_))
+        ));
+        assert!(matches!(
+            pool.apply_swap(sqrt_price_x96, LIQUIDITY, i32::MAX),
+            Err(Error::InvalidTick(tick)) if tick == I24::MAX
+        ));
+        assert!(matches!(
+            pool.apply_swap(sqrt_price_x96, LIQUIDITY, i32::MIN),
+            Err(Error::InvalidTick(tick)) if tick == I24::MIN
+        ));
+        assert_eq!(pool.tick_current, 99);
+    }
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        #[test]
+        fn get_output_amount_matches_reference_values() {
+            let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+            for token in [&pool.token0, &pool.token1] {
+                for (amount_in, amount_out) in [
+                    (1, 0),
+                    (100, 98),
+                    (1_000, 996),
+                    (10_000, 9_871),
+                    (100_000, 90_661),
+                ] {
+                    let output = pool
+                        .get_output_amount(
+                            &CurrencyAmount::from_raw_amount(token.clone(), amount_in).unwrap(),
+                            None,
+                        )
+                        .unwrap();
+                    assert_eq!(output.quotient(), amount_out.into());
+                }
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn get_output_amount_is_monotonic_in_input(
+                amount in 1_u64..100_000,
+                delta in 1_u64..1_000,
+                zero_for_one: bool,
+            ) {
+                let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+                let token = if zero_for_one { &pool.token0 } else { &pool.token1 };
+                let output = |amount: u64| {
+                    pool.get_output_amount(
+                        &CurrencyAmount::from_raw_amount(token.clone(), amount).unwrap(),
+                        None,
+                    )
+                    .unwrap()
+                    .quotient()
+                };
+                assert!(output(amount) <= output(amount + delta));
+            }
+        }
+    }
 }
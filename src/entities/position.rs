@@ -75,6 +75,63 @@ impl<TP: TickDataProvider> Position<TP> {
         }
     }
 
+    /// Constructs a position like [`Position::new`], but first snaps each tick to the nearest
+    /// usable tick for the pool's tick spacing.
+    ///
+    /// Note that this changes the range of the position, read the snapped bounds back from
+    /// [`Position::tick_lower`] and [`Position::tick_upper`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool`: For which pool the liquidity is assigned
+    /// * `liquidity`: The amount of liquidity that is in the position
+    /// * `tick_lower`: The lower tick of the position, before snapping
+    /// * `tick_upper`: The upper tick of the position, before snapping
+    #[inline]
+    pub fn new_snapped(
+        pool: Pool<TP>,
+        liquidity: u128,
+        tick_lower: TP::Index,
+        tick_upper: TP::Index,
+    ) -> Self {
+        let tick_spacing = pool.tick_spacing();
+        Self::new(
+            pool,
+            liquidity,
+            nearest_usable_tick(tick_lower, tick_spacing),
+            nearest_usable_tick(tick_upper, tick_spacing),
+        )
+    }
+
+    /// Constructs a position from the decoded return value of the nonfungible position manager's
+    /// `positions(tokenId)` call
+    ///
+    /// Only the tick bounds and liquidity are kept. The nonce, operator, fee growth and tokens owed
+    /// are not needed for the economic computations of a position and are ignored.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool`: The pool the position belongs to, must match the tokens and fee of the position
+    /// * `positions`: The decoded `positions(tokenId)` return value
+    #[inline]
+    pub fn from_positions_tuple(
+        pool: Pool<TP>,
+        positions: &INonfungiblePositionManager::positionsReturn,
+    ) -> Self {
+        assert!(
+            pool.token0.address() == positions.token0
+                && pool.token1.address() == positions.token1
+                && pool.fee == positions.fee.into(),
+            "POOL"
+        );
+        Self::new(
+            pool,
+            positions.liquidity,
+            TP::Index::from_i24(positions.tickLower),
+            TP::Index::from_i24(positions.tickUpper),
+        )
+    }
+
     /// Returns the price of token0 at the lower tick
     #[inline]
     pub fn token0_price_lower(&self) -> Result<Price<Token, Token>, Error> {
@@ -95,6 +152,84 @@ impl<TP: TickDataProvider> Position<TP> {
         )
     }
 
+    /// Returns the sqrt ratio as a Q64.96 at the lower tick, or [`Error::InvalidTick`] if
+    /// `tick_lower` was set outside of [`MIN_TICK`, `MAX_TICK`] after [`Position::new`]
+    #[inline]
+    pub fn sqrt_ratio_lower(&self) -> Result<U160, Error> {
+        get_sqrt_ratio_at_tick(self.tick_lower.to_i24_saturating())
+    }
+
+    /// Returns the sqrt ratio as a Q64.96 at the upper tick, or [`Error::InvalidTick`] if
+    /// `tick_upper` was set outside of [`MIN_TICK`, `MAX_TICK`] after [`Position::new`]
+    #[inline]
+    pub fn sqrt_ratio_upper(&self) -> Result<U160, Error> {
+        get_sqrt_ratio_at_tick(self.tick_upper.to_i24_saturating())
+    }
+
+    /// Returns the lower and upper bounds of the position's range relative to the current pool
+    /// price, e.g. `(-5%, +5%)`. Both bounds have the same sign if the position is out of range.
+    #[inline]
+    pub fn range_width_percent(&self) -> Result<(Percent, Percent), Error> {
+        let current = self.pool.token0_price();
+        let relative = |price: Price<Token, Token>| {
+            let denominator = price.denominator * current.numerator;
+            Percent::new(
+                price.numerator * current.denominator - denominator,
+                denominator,
+            )
+        };
+        Ok((
+            relative(self.token0_price_lower()?),
+            relative(self.token0_price_upper()?),
+        ))
+    }
+
+    /// Returns whether the tick range of this position overlaps the tick range of another position
+    /// of a pool with the same tokens. Adjacent ranges sharing only a bound do not overlap.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other`: The position to compare against, possibly with a different tick data provider
+    #[inline]
+    pub fn overlaps<Q: TickDataProvider>(&self, other: &Position<Q>) -> bool {
+        assert!(
+            self.pool.token0.equals(&other.pool.token0)
+                && self.pool.token1.equals(&other.pool.token1),
+            "TOKENS"
+        );
+        self.tick_lower.to_i24() < other.tick_upper.to_i24()
+            && other.tick_lower.to_i24() < self.tick_upper.to_i24()
+    }
+
+    /// Computes the fees owed to the position since the last fee growth snapshot with
+    /// [`get_tokens_owed`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `fee_growth_inside0_last_x128`: The token0 fee growth inside the range at the last update
+    /// * `fee_growth_inside1_last_x128`: The token1 fee growth inside the range at the last update
+    /// * `fee_growth_inside0_x128`: The current token0 fee growth inside the range
+    /// * `fee_growth_inside1_x128`: The current token1 fee growth inside the range
+    ///
+    /// returns: The token0 and token1 fees owed
+    #[inline]
+    #[must_use]
+    pub fn fees_owed(
+        &self,
+        fee_growth_inside0_last_x128: U256,
+        fee_growth_inside1_last_x128: U256,
+        fee_growth_inside0_x128: U256,
+        fee_growth_inside1_x128: U256,
+    ) -> (U256, U256) {
+        get_tokens_owed(
+            fee_growth_inside0_last_x128,
+            fee_growth_inside1_last_x128,
+            self.liquidity,
+            fee_growth_inside0_x128,
+            fee_growth_inside1_x128,
+        )
+    }
+
     /// Returns the amount of token0 that this position's liquidity could be burned for at the
     /// current pool price
     #[inline]
@@ -183,6 +318,22 @@ impl<TP: TickDataProvider> Position<TP> {
         Ok(amount)
     }
 
+    /// Returns the amounts of token0 and token1 that this position's liquidity could be burned for
+    /// if the pool were at the given price, mirroring `LiquidityAmounts.getAmountsForLiquidity`
+    ///
+    /// ## Arguments
+    ///
+    /// * `sqrt_ratio_x96`: The hypothetical sqrt price of the pool
+    #[inline]
+    pub fn amounts_at_sqrt_ratio(&self, sqrt_ratio_x96: U160) -> Result<(U256, U256), Error> {
+        get_amounts_for_liquidity(
+            sqrt_ratio_x96,
+            get_sqrt_ratio_at_tick(self.tick_lower.to_i24())?,
+            get_sqrt_ratio_at_tick(self.tick_upper.to_i24())?,
+            self.liquidity,
+        )
+    }
+
     /// Returns the lower and upper sqrt ratios if the price 'slips' up to slippage tolerance
     /// percentage
     ///
@@ -253,16 +404,10 @@ impl<TP: TickDataProvider> Position<TP> {
         // Because the router is imprecise, we need to calculate the position that will be created
         // (assuming no slippage)
         let MintAmounts { amount0, amount1 } = self.mint_amounts_cached()?;
-        let position_that_will_be_created = Position::from_amounts(
-            Pool::new(
-                self.pool.token0.clone(),
-                self.pool.token1.clone(),
-                self.pool.fee,
-                self.pool.sqrt_ratio_x96,
-                self.pool.liquidity,
-            )?,
-            self.tick_lower.try_into().unwrap(),
-            self.tick_upper.try_into().unwrap(),
+        let liquidity = Self::liquidity_for_amounts(
+            self.pool.sqrt_ratio_x96,
+            self.tick_lower,
+            self.tick_upper,
             amount0,
             amount1,
             false,
@@ -272,7 +417,7 @@ impl<TP: TickDataProvider> Position<TP> {
         // ...which occurs at the upper price for amount0...
         let amount0 = Position::new(
             pool_upper,
-            position_that_will_be_created.liquidity,
+            liquidity,
             self.tick_lower.try_into().unwrap(),
             self.tick_upper.try_into().unwrap(),
         )
@@ -281,7 +426,7 @@ impl<TP: TickDataProvider> Position<TP> {
         // ...and the lower for amount1
         let amount1 = Position::new(
             pool_lower,
-            position_that_will_be_created.liquidity,
+            liquidity,
             self.tick_lower.try_into().unwrap(),
             self.tick_upper.try_into().unwrap(),
         )
@@ -351,15 +496,30 @@ impl<TP: TickDataProvider> Position<TP> {
 
     /// Returns the minimum amounts that must be sent in order to mint the amount of liquidity held
     /// by the position at the current price for the pool
+    ///
+    /// The amounts are rounded up, as the pool does when minting, so that enough is always
+    /// provided. See [`Position::mint_amounts_rounded_down`] for the conservative direction.
     #[inline]
     pub fn mint_amounts(&self) -> Result<MintAmounts, Error> {
+        self.mint_amounts_rounded(true)
+    }
+
+    /// Returns the amounts of the liquidity held by the position at the current price for the
+    /// pool, rounded down. These may fall short of what the pool requires to mint the position by
+    /// at most a few wei, see [`Position::mint_amounts`].
+    #[inline]
+    pub fn mint_amounts_rounded_down(&self) -> Result<MintAmounts, Error> {
+        self.mint_amounts_rounded(false)
+    }
+
+    fn mint_amounts_rounded(&self, round_up: bool) -> Result<MintAmounts, Error> {
         Ok(if self.pool.tick_current < self.tick_lower {
             MintAmounts {
                 amount0: get_amount_0_delta(
                     get_sqrt_ratio_at_tick(self.tick_lower.to_i24())?,
                     get_sqrt_ratio_at_tick(self.tick_upper.to_i24())?,
                     self.liquidity,
-                    true,
+                    round_up,
                 )?,
                 amount1: U256::ZERO,
             }
@@ -369,13 +529,13 @@ impl<TP: TickDataProvider> Position<TP> {
                     self.pool.sqrt_ratio_x96,
                     get_sqrt_ratio_at_tick(self.tick_upper.to_i24())?,
                     self.liquidity,
-                    true,
+                    round_up,
                 )?,
                 amount1: get_amount_1_delta(
                     get_sqrt_ratio_at_tick(self.tick_lower.to_i24())?,
                     self.pool.sqrt_ratio_x96,
                     self.liquidity,
-                    true,
+                    round_up,
                 )?,
             }
         } else {
@@ -385,7 +545,7 @@ impl<TP: TickDataProvider> Position<TP> {
                     get_sqrt_ratio_at_tick(self.tick_lower.to_i24())?,
                     get_sqrt_ratio_at_tick(self.tick_upper.to_i24())?,
                     self.liquidity,
-                    true,
+                    round_up,
                 )?,
             }
         })
@@ -403,6 +563,54 @@ impl<TP: TickDataProvider> Position<TP> {
         Ok(amounts)
     }
 
+    /// Returns the minimum amounts that must be sent in order to mint the amount of liquidity held
+    /// by the position at the current price for the pool, as currency amounts of the pool tokens
+    #[inline]
+    pub fn mint_currency_amounts(
+        &self,
+    ) -> Result<(CurrencyAmount<Token>, CurrencyAmount<Token>), Error> {
+        let MintAmounts { amount0, amount1 } = self.mint_amounts()?;
+        Ok((
+            CurrencyAmount::from_raw_amount(self.pool.token0.clone(), amount0.to_big_int())?,
+            CurrencyAmount::from_raw_amount(self.pool.token1.clone(), amount1.to_big_int())?,
+        ))
+    }
+
+    /// Computes the maximum amount of liquidity received for a given amount of token0, token1,
+    /// and the prices at the tick boundaries, without validating the inputs.
+    #[inline]
+    fn liquidity_for_amounts(
+        sqrt_ratio_x96: U160,
+        tick_lower: TP::Index,
+        tick_upper: TP::Index,
+        amount0: U256,
+        amount1: U256,
+        use_full_precision: bool,
+    ) -> Result<u128, Error> {
+        let sqrt_ratio_a_x96 = get_sqrt_ratio_at_tick(tick_lower.to_i24())?;
+        let sqrt_ratio_b_x96 = get_sqrt_ratio_at_tick(tick_upper.to_i24())?;
+        if use_full_precision {
+            max_liquidity_for_amounts(
+                sqrt_ratio_x96,
+                sqrt_ratio_a_x96,
+                sqrt_ratio_b_x96,
+                amount0,
+                amount1,
+                true,
+            )
+            .to_u128()
+            .ok_or(Error::SafeCastToU128Overflow)
+        } else {
+            get_liquidity_for_amounts(
+                sqrt_ratio_x96,
+                sqrt_ratio_a_x96,
+                sqrt_ratio_b_x96,
+                amount0,
+                amount1,
+            )
+        }
+    }
+
     /// Computes the maximum amount of liquidity received for a given amount of token0, token1,
     /// and the prices at the tick boundaries.
     ///
@@ -418,7 +626,11 @@ impl<TP: TickDataProvider> Position<TP> {
     ///
     /// ## Returns
     ///
-    /// The position with the maximum amount of liquidity received
+    /// The position with the maximum amount of liquidity received, or
+    /// - [`Error::InvalidRange`] if `tick_lower` is not below `tick_upper`
+    /// - [`Error::InvalidTick`] if a tick is out of bounds or not a multiple of the tick spacing
+    /// - [`Error::ZeroAmount`] if both amounts are zero
+    /// - [`Error::SafeCastToU128Overflow`] if the liquidity does not fit in a `u128`
     #[inline]
     pub fn from_amounts(
         pool: Pool<TP>,
@@ -428,27 +640,53 @@ impl<TP: TickDataProvider> Position<TP> {
         amount1: U256,
         use_full_precision: bool,
     ) -> Result<Self, Error> {
-        let sqrt_ratio_a_x96 = get_sqrt_ratio_at_tick(tick_lower.to_i24())?;
-        let sqrt_ratio_b_x96 = get_sqrt_ratio_at_tick(tick_upper.to_i24())?;
-        let liquidity = max_liquidity_for_amounts(
+        Self::validate_ticks(&pool, tick_lower, tick_upper)?;
+        if amount0.is_zero() && amount1.is_zero() {
+            return Err(Error::ZeroAmount);
+        }
+        let liquidity = Self::liquidity_for_amounts(
             pool.sqrt_ratio_x96,
-            sqrt_ratio_a_x96,
-            sqrt_ratio_b_x96,
+            tick_lower,
+            tick_upper,
             amount0,
             amount1,
             use_full_precision,
-        );
-        Ok(Self::new(
-            pool,
-            liquidity.to_u128().unwrap(),
-            tick_lower,
-            tick_upper,
-        ))
+        )?;
+        Ok(Self::new(pool, liquidity, tick_lower, tick_upper))
+    }
+
+    /// Checks the ticks of a position against the pool, returning the errors documented on
+    /// [`Position::from_amounts`] instead of panicking like [`Position::new`]
+    fn validate_ticks(
+        pool: &Pool<TP>,
+        tick_lower: TP::Index,
+        tick_upper: TP::Index,
+    ) -> Result<(), Error> {
+        if tick_lower >= tick_upper {
+            return Err(Error::InvalidRange);
+        }
+        let tick_spacing = pool.tick_spacing();
+        if tick_lower < TP::Index::from_i24(MIN_TICK) {
+            return Err(Error::InvalidTick(tick_lower.to_i24_saturating()));
+        }
+        if tick_upper > TP::Index::from_i24(MAX_TICK) {
+            return Err(Error::InvalidTick(tick_upper.to_i24_saturating()));
+        }
+        if !(tick_lower % tick_spacing).is_zero() {
+            return Err(Error::InvalidTick(tick_lower.to_i24()));
+        }
+        if !(tick_upper % tick_spacing).is_zero() {
+            return Err(Error::InvalidTick(tick_upper.to_i24()));
+        }
+        Ok(())
     }
 
     /// Computes a position with the maximum amount of liquidity received for a given amount of
     /// token0, assuming an unlimited amount of token1
     ///
+    /// Returns the same errors as [`Position::from_amounts`], and [`Error::ZeroAmount`] if
+    /// `amount0` is zero.
+    ///
     /// ## Arguments
     ///
     /// * `pool`: The pool for which the position is created
@@ -465,6 +703,9 @@ impl<TP: TickDataProvider> Position<TP> {
         amount0: U256,
         use_full_precision: bool,
     ) -> Result<Self, Error> {
+        if amount0.is_zero() {
+            return Err(Error::ZeroAmount);
+        }
         Self::from_amounts(
             pool,
             tick_lower,
@@ -478,6 +719,9 @@ impl<TP: TickDataProvider> Position<TP> {
     /// Computes a position with the maximum amount of liquidity received for a given amount of
     /// token1, assuming an unlimited amount of token0
     ///
+    /// Returns the same errors as [`Position::from_amounts`], and [`Error::ZeroAmount`] if
+    /// `amount1` is zero.
+    ///
     /// ## Arguments
     ///
     /// * `pool`: The pool for which the position is created
@@ -491,9 +735,141 @@ impl<TP: TickDataProvider> Position<TP> {
         tick_upper: TP::Index,
         amount1: U256,
     ) -> Result<Self, Error> {
+        if amount1.is_zero() {
+            return Err(Error::ZeroAmount);
+        }
         // this function always uses full precision
         Self::from_amounts(pool, tick_lower, tick_upper, U256::MAX, amount1, true)
     }
+
+    /// Computes a position with the maximum amount of liquidity received for a given amount of
+    /// token0 and token1 within a price range
+    ///
+    /// The prices may be in either orientation, e.g. USDC per WETH or WETH per USDC. Each bound is
+    /// converted to the closest tick at or below its price, then snapped outwards to a usable tick.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool`: The pool for which the position is created
+    /// * `price_lower`: One bound of the price range
+    /// * `price_upper`: The other bound of the price range
+    /// * `amount0`: token0 amount
+    /// * `amount1`: token1 amount
+    /// * `use_full_precision`: If false, liquidity will be maximized according to what the router
+    ///   can calculate, not what core can theoretically support
+    ///
+    /// ## Returns
+    ///
+    /// The position, or the errors of [`Position::from_amounts`]
+    #[inline]
+    pub fn from_price_range(
+        pool: Pool<TP>,
+        price_lower: &Price<Token, Token>,
+        price_upper: &Price<Token, Token>,
+        amount0: U256,
+        amount1: U256,
+        use_full_precision: bool,
+    ) -> Result<Self, Error> {
+        let tick_lower = pool
+            .tick_for_price(price_lower, Rounding::RoundDown)?
+            .min(pool.tick_for_price(price_upper, Rounding::RoundDown)?);
+        let tick_upper = pool
+            .tick_for_price(price_lower, Rounding::RoundUp)?
+            .max(pool.tick_for_price(price_upper, Rounding::RoundUp)?);
+        Self::from_amounts(
+            pool,
+            TP::Index::from_i24(tick_lower.to_i24()),
+            TP::Index::from_i24(tick_upper.to_i24()),
+            amount0,
+            amount1,
+            use_full_precision,
+        )
+    }
+
+    /// Computes the maximum liquidity received for a given amount of token0 and the amount of
+    /// token1 that must be sent alongside it to mint that liquidity at the current price
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool`: The pool for which the position is created
+    /// * `tick_lower`: The lower tick
+    /// * `tick_upper`: The upper tick
+    /// * `amount0`: The desired amount of token0
+    ///
+    /// ## Returns
+    ///
+    /// The liquidity and the required amount of token1
+    #[inline]
+    pub fn liquidity_and_pair_amount_from_amount0(
+        pool: Pool<TP>,
+        tick_lower: TP::Index,
+        tick_upper: TP::Index,
+        amount0: U256,
+    ) -> Result<(u128, U256), Error> {
+        let position = Self::from_amount0(pool, tick_lower, tick_upper, amount0, true)?;
+        let MintAmounts { amount1, .. } = position.mint_amounts()?;
+        Ok((position.liquidity, amount1))
+    }
+}
+
+/// With the `serde` feature, a position is serialized through its pool, ticks and liquidity. The
+/// ticks are validated against the pool on deserialization.
+#[cfg(feature = "serde")]
+mod position_serde {
+    use super::*;
+    use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(bound(serialize = "TP: Serialize, TP::Index: Serialize"))]
+    struct PositionRef<'a, TP: TickDataProvider> {
+        pool: &'a Pool<TP>,
+        tick_lower: TP::Index,
+        tick_upper: TP::Index,
+        liquidity: u128,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(bound(deserialize = "TP: Deserialize<'de>, TP::Index: Deserialize<'de>"))]
+    struct PositionRepr<TP: TickDataProvider> {
+        pool: Pool<TP>,
+        tick_lower: TP::Index,
+        tick_upper: TP::Index,
+        liquidity: u128,
+    }
+
+    impl<TP> Serialize for Position<TP>
+    where
+        TP: TickDataProvider<Index: Serialize> + Serialize,
+    {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PositionRef {
+                pool: &self.pool,
+                tick_lower: self.tick_lower,
+                tick_upper: self.tick_upper,
+                liquidity: self.liquidity,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, TP> Deserialize<'de> for Position<TP>
+    where
+        TP: TickDataProvider<Index: Deserialize<'de>> + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let position = PositionRepr::<TP>::deserialize(deserializer)?;
+            Self::validate_ticks(&position.pool, position.tick_lower, position.tick_upper)
+                .map_err(D::Error::custom)?;
+            Ok(Self::new(
+                position.pool,
+                position.liquidity,
+                position.tick_lower,
+                position.tick_upper,
+            ))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -585,6 +961,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_snapped_snaps_unaligned_ticks() {
+        let position = Position::new_snapped(DAI_USDC_POOL.clone(), 1, -14, 16);
+        assert_eq!(position.tick_lower, -10);
+        assert_eq!(position.tick_upper, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "TICK_ORDER")]
+    fn new_snapped_throws_if_ticks_snap_to_the_same_tick() {
+        Position::new_snapped(DAI_USDC_POOL.clone(), 1, -4, 4);
+    }
+
+    #[test]
+    fn overlaps_compares_tick_ranges() {
+        let position = Position::new(DAI_USDC_POOL.clone(), 1, -20, 20);
+        // overlapping
+        assert!(position.overlaps(&Position::new(DAI_USDC_POOL.clone(), 1, 10, 30)));
+        assert!(position.overlaps(&Position::new(DAI_USDC_POOL.clone(), 1, -10, 10)));
+        // adjacent
+        assert!(!position.overlaps(&Position::new(DAI_USDC_POOL.clone(), 1, 20, 40)));
+        assert!(!position.overlaps(&Position::new(DAI_USDC_POOL.clone(), 1, -40, -20)));
+        // disjoint
+        assert!(!position.overlaps(&Position::new(DAI_USDC_POOL.clone(), 1, 30, 50)));
+    }
+
+    #[test]
+    fn can_be_constructed_from_positions_tuple() {
+        let positions = INonfungiblePositionManager::positionsReturn {
+            nonce: Default::default(),
+            operator: Address::ZERO,
+            token0: DAI.address(),
+            token1: USDC.address(),
+            fee: FeeAmount::LOW.into(),
+            tickLower: -TICK_SPACING,
+            tickUpper: TICK_SPACING,
+            liquidity: 100e18 as u128,
+            feeGrowthInside0LastX128: U256::from(1),
+            feeGrowthInside1LastX128: U256::from(2),
+            tokensOwed0: 3,
+            tokensOwed1: 4,
+        };
+        let position: Position = Position::from_positions_tuple(DAI_USDC_POOL.clone(), &positions);
+        assert_eq!(
+            position,
+            Position::new(DAI_USDC_POOL.clone(), 100e18 as u128, -10, 10)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "POOL")]
+    fn from_positions_tuple_throws_if_pool_does_not_match() {
+        let positions = INonfungiblePositionManager::positionsReturn {
+            nonce: Default::default(),
+            operator: Address::ZERO,
+            token0: DAI.address(),
+            token1: USDC.address(),
+            fee: FeeAmount::MEDIUM.into(),
+            tickLower: -I24::from_limbs([60]),
+            tickUpper: I24::from_limbs([60]),
+            liquidity: 1,
+            feeGrowthInside0LastX128: U256::ZERO,
+            feeGrowthInside1LastX128: U256::ZERO,
+            tokensOwed0: 0,
+            tokensOwed1: 0,
+        };
+        let _: Position = Position::from_positions_tuple(DAI_USDC_POOL.clone(), &positions);
+    }
+
+    #[test]
+    fn sqrt_ratios_match_the_tick_bounds() {
+        let position = Position::new(DAI_USDC_POOL.clone(), 1, -120, 180);
+        assert_eq!(
+            position.sqrt_ratio_lower().unwrap(),
+            get_sqrt_ratio_at_tick(I24::try_from(-120).unwrap()).unwrap()
+        );
+        assert_eq!(
+            position.sqrt_ratio_upper().unwrap(),
+            get_sqrt_ratio_at_tick(I24::try_from(180).unwrap()).unwrap()
+        );
+
+        let mut position = position;
+        position.tick_upper = MAX_TICK_I32 + 60;
+        assert!(matches!(
+            position.sqrt_ratio_upper(),
+            Err(Error::InvalidTick(_))
+        ));
+    }
+
     #[test]
     fn amount0_is_correct_for_price_above() {
         let position = Position::new(
@@ -663,6 +1128,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn range_width_percent_is_correct_for_symmetric_range() {
+        let pool = Pool::new(
+            DAI.clone(),
+            USDC.clone(),
+            FeeAmount::LOW,
+            encode_sqrt_ratio_x96(1, 1),
+            0,
+        )
+        .unwrap();
+        // 1.0001^490 ~= 1.05
+        let position = Position::new(pool, 1, -490, 490);
+        let (lower, upper) = position.range_width_percent().unwrap();
+        assert!(lower.as_fraction() > Percent::new(-48, 1000).as_fraction());
+        assert!(lower.as_fraction() < Percent::new(-47, 1000).as_fraction());
+        assert!(upper.as_fraction() > Percent::new(50, 1000).as_fraction());
+        assert!(upper.as_fraction() < Percent::new(51, 1000).as_fraction());
+    }
+
+    #[test]
+    fn range_width_percent_is_positive_for_position_above_price() {
+        let pool = Pool::new(
+            DAI.clone(),
+            USDC.clone(),
+            FeeAmount::LOW,
+            encode_sqrt_ratio_x96(1, 1),
+            0,
+        )
+        .unwrap();
+        let position = Position::new(pool, 1, 490, 980);
+        let (lower, upper) = position.range_width_percent().unwrap();
+        assert!(lower.as_fraction() > Percent::new(0, 1).as_fraction());
+        assert!(upper.as_fraction() > lower.as_fraction());
+    }
+
+    #[test]
+    fn amounts_at_sqrt_ratio_below_range() {
+        let position = Position::new(
+            DAI_USDC_POOL.clone(),
+            100e12 as u128,
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING).as_i32(),
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING * TWO).as_i32(),
+        );
+        let (amount0, amount1) = position
+            .amounts_at_sqrt_ratio(*POOL_SQRT_RATIO_START)
+            .unwrap();
+        assert_eq!(amount0.to_string(), "49949961958869841");
+        assert_eq!(amount1, U256::ZERO);
+    }
+
+    #[test]
+    fn amounts_at_sqrt_ratio_in_range() {
+        let position = Position::new(
+            DAI_USDC_POOL.clone(),
+            100e18 as u128,
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING * TWO).as_i32(),
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING * TWO).as_i32(),
+        );
+        let (amount0, amount1) = position
+            .amounts_at_sqrt_ratio(*POOL_SQRT_RATIO_START)
+            .unwrap();
+        assert_eq!(amount0.to_string(), "120054069145287995769396");
+        assert_eq!(amount1.to_string(), "79831926242");
+    }
+
+    #[test]
+    fn amounts_at_sqrt_ratio_above_range() {
+        let position = Position::new(
+            DAI_USDC_POOL.clone(),
+            100e18 as u128,
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING * TWO).as_i32(),
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING).as_i32(),
+        );
+        let (amount0, amount1) = position
+            .amounts_at_sqrt_ratio(*POOL_SQRT_RATIO_START)
+            .unwrap();
+        assert_eq!(amount0, U256::ZERO);
+        assert_eq!(amount1.to_string(), "49970077052");
+    }
+
     #[test]
     fn mint_amounts_with_slippage_is_correct_for_positions_below() {
         let mut position = Position::new(
@@ -971,4 +1516,233 @@ mod tests {
         assert_eq!(amount0.to_string(), "120054069145287995769397");
         assert_eq!(amount1.to_string(), "79831926243");
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn round_trips_through_serde_with_tick_data() {
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        let tick_spacing = pool.tick_spacing();
+        let position = Position::new(pool, 1000, -tick_spacing * 2, tick_spacing * 2);
+        let json = serde_json::to_string(&position).unwrap();
+        let restored: Position<TickListDataProvider> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, position);
+        assert_eq!(
+            restored.pool.tick_data_provider,
+            position.pool.tick_data_provider
+        );
+
+        let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap();
+        assert_eq!(
+            restored
+                .pool
+                .get_output_amount(&input_amount, None)
+                .unwrap(),
+            position
+                .pool
+                .get_output_amount(&input_amount, None)
+                .unwrap()
+        );
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["tick_lower"] = (-tick_spacing * 2 + 1).into();
+        assert!(serde_json::from_value::<Position<TickListDataProvider>>(value).is_err());
+    }
+
+    #[test]
+    fn mint_amounts_rounded_down_differs_from_mint_amounts_at_fractional_amounts() {
+        let position = Position::new(
+            DAI_USDC_POOL.clone(),
+            100e18 as u128,
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING * TWO).as_i32(),
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING * TWO).as_i32(),
+        );
+        let MintAmounts { amount0, amount1 } = position.mint_amounts_rounded_down().unwrap();
+        assert_eq!(amount0.to_string(), "120054069145287995769396");
+        assert_eq!(amount1.to_string(), "79831926242");
+        let rounded_up = position.mint_amounts().unwrap();
+        assert_eq!(rounded_up.amount0, amount0 + U256::from(1));
+        assert_eq!(rounded_up.amount1, amount1 + U256::from(1));
+    }
+
+    #[test]
+    fn liquidity_and_pair_amount_from_amount0_for_positions_within() {
+        let tick_lower =
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING * TWO).as_i32();
+        let tick_upper =
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING * TWO).as_i32();
+        let amount0 = U256::from(10_u128.pow(20));
+        let (liquidity, amount1) = Position::liquidity_and_pair_amount_from_amount0(
+            DAI_USDC_POOL.clone(),
+            tick_lower,
+            tick_upper,
+            amount0,
+        )
+        .unwrap();
+        let position = Position::new(DAI_USDC_POOL.clone(), liquidity, tick_lower, tick_upper);
+        let mint_amounts = position.mint_amounts().unwrap();
+        assert!(liquidity > 0);
+        assert!(mint_amounts.amount0 <= amount0);
+        assert_eq!(mint_amounts.amount1, amount1);
+        assert!(amount1 > U256::ZERO);
+    }
+
+    #[test]
+    fn mint_currency_amounts_matches_mint_amounts() {
+        let position = Position::new(
+            DAI_USDC_POOL.clone(),
+            100e18 as u128,
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING * TWO).as_i32(),
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING * TWO).as_i32(),
+        );
+        let MintAmounts { amount0, amount1 } = position.mint_amounts().unwrap();
+        let (currency_amount0, currency_amount1) = position.mint_currency_amounts().unwrap();
+        assert_eq!(currency_amount0.currency, DAI.clone());
+        assert_eq!(currency_amount1.currency, USDC.clone());
+        assert_eq!(U256::from_big_int(currency_amount0.quotient()), amount0);
+        assert_eq!(U256::from_big_int(currency_amount1.quotient()), amount1);
+    }
+
+    #[test]
+    fn from_amounts_uses_the_liquidity_amounts_library() {
+        let tick_lower =
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) - TICK_SPACING * TWO).as_i32();
+        let tick_upper =
+            (nearest_usable_tick(*POOL_TICK_CURRENT, TICK_SPACING) + TICK_SPACING * TWO).as_i32();
+        let amount0 = U256::from(10_u128.pow(20));
+        let amount1 = U256::from(10_u128.pow(8));
+        let position = Position::from_amounts(
+            DAI_USDC_POOL.clone(),
+            tick_lower,
+            tick_upper,
+            amount0,
+            amount1,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            position.liquidity,
+            get_liquidity_for_amounts(
+                *POOL_SQRT_RATIO_START,
+                get_sqrt_ratio_at_tick(tick_lower.to_i24()).unwrap(),
+                get_sqrt_ratio_at_tick(tick_upper.to_i24()).unwrap(),
+                amount0,
+                amount1,
+            )
+            .unwrap()
+        );
+        let mint_amounts = position.mint_amounts().unwrap();
+        assert!(mint_amounts.amount0 <= amount0);
+        assert!(mint_amounts.amount1 <= amount1);
+    }
+
+    #[test]
+    fn from_amounts_rejects_invalid_inputs() {
+        let amount = U256::from(100);
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), 10, -10, amount, amount, true),
+            Err(Error::InvalidRange)
+        ));
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), 10, 10, amount, amount, true),
+            Err(Error::InvalidRange)
+        ));
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), -15, 10, amount, amount, true),
+            Err(Error::InvalidTick(tick)) if tick == (-15_i32).to_i24()
+        ));
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), -10, 15, amount, amount, true),
+            Err(Error::InvalidTick(tick)) if tick == 15_i32.to_i24()
+        ));
+        // the out of range tick is reported rather than the bound it crosses
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), -887280, 10, amount, amount, true),
+            Err(Error::InvalidTick(tick)) if tick == (-887280_i32).to_i24()
+        ));
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), -10, i32::MAX - 7, amount, amount, true),
+            Err(Error::InvalidTick(tick)) if tick == I24::MAX
+        ));
+        assert!(matches!(
+            Position::from_amounts(DAI_USDC_POOL.clone(), -10, 10, U256::ZERO, U256::ZERO, true),
+            Err(Error::ZeroAmount)
+        ));
+        assert!(matches!(
+            Position::from_amount0(DAI_USDC_POOL.clone(), -10, 10, U256::ZERO, true),
+            Err(Error::ZeroAmount)
+        ));
+        assert!(matches!(
+            Position::from_amount1(DAI_USDC_POOL.clone(), -10, 10, U256::ZERO),
+            Err(Error::ZeroAmount)
+        ));
+    }
+
+    #[test]
+    fn fees_owed_matches_the_contract() {
+        let position = Position::new(DAI_USDC_POOL.clone(), 1000, -10, 10);
+        assert_eq!(
+            position.fees_owed(U256::ZERO, Q128, Q128 * U256::from(3), Q128 + (Q128 >> 1)),
+            (U256::from(3000), U256::from(500))
+        );
+    }
+
+    #[test]
+    fn from_price_range_with_weth_usdc_prices() {
+        // 3000 USDC per WETH, with USDC as token0
+        let pool = Pool::new(
+            USDC.clone(),
+            WETH.clone(),
+            FeeAmount::LOW,
+            encode_sqrt_ratio_x96(BigInt::from(10).pow(18), 3000 * 10_u64.pow(6)),
+            0,
+        )
+        .unwrap();
+        let usdc_per_weth = |usdc: u64| {
+            Price::new(
+                WETH.clone(),
+                USDC.clone(),
+                BigInt::from(10).pow(18),
+                usdc * 10_u64.pow(6),
+            )
+        };
+        let amount0 = U256::from(3000 * 10_u64.pow(6));
+        let amount1 = U256::from(10_u64.pow(18));
+        let position = Position::from_price_range(
+            pool,
+            &usdc_per_weth(2800),
+            &usdc_per_weth(3200),
+            amount0,
+            amount1,
+            false,
+        )
+        .unwrap();
+
+        assert!(position.liquidity > 0);
+        let tick_spacing = FeeAmount::LOW.tick_spacing().as_i32();
+        assert_eq!(position.tick_lower % tick_spacing, 0);
+        assert_eq!(position.tick_upper % tick_spacing, 0);
+        assert!(position.tick_upper - position.tick_lower > tick_spacing);
+        // token0 is USDC so the price of WETH in USDC decreases with the tick
+        let lowest = position.token0_price_upper().unwrap().invert();
+        let highest = position.token0_price_lower().unwrap().invert();
+        assert!(lowest.as_fraction() < usdc_per_weth(2801).as_fraction());
+        assert!(lowest.as_fraction() > usdc_per_weth(2790).as_fraction());
+        assert!(highest.as_fraction() > usdc_per_weth(3199).as_fraction());
+        assert!(highest.as_fraction() < usdc_per_weth(3210).as_fraction());
+        let mint_amounts = position.mint_amounts().unwrap();
+        assert!(mint_amounts.amount0 <= amount0);
+        assert!(mint_amounts.amount1 <= amount1);
+
+        // the orientation of the prices does not matter
+        let inverted = Position::from_price_range(
+            position.pool.clone(),
+            &usdc_per_weth(3200).invert(),
+            &usdc_per_weth(2800).invert(),
+            amount0,
+            amount1,
+            false,
+        )
+        .unwrap();
+        assert_eq!(inverted, position);
+    }
 }
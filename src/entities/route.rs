@@ -68,6 +68,26 @@ where
         }
     }
 
+    /// Creates a route whose input and/or output currency may be native while its pools hold the
+    /// wrapped token.
+    ///
+    /// This is equivalent to [`Route::new`], which already validates the wrapped form of each
+    /// currency against the adjacent pool token, and exists to make the intent explicit.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pools`: An array of [`Pool`] objects, ordered by the route the swap will take
+    /// * `input_currency`: The input currency, possibly native
+    /// * `output_currency`: The output currency, possibly native
+    #[inline]
+    pub fn new_with_currencies(
+        pools: Vec<Pool<TP>>,
+        input_currency: TInput,
+        output_currency: TOutput,
+    ) -> Self {
+        Self::new(pools, input_currency, output_currency)
+    }
+
     /// Returns the path of tokens that the route will take
     #[inline]
     pub fn token_path(&self) -> Vec<Token> {
@@ -84,12 +104,42 @@ where
         token_path
     }
 
+    /// Returns the pools that the route will swap through, in order
+    #[inline]
+    pub fn pool_path(&self) -> &[Pool<TP>] {
+        &self.pools
+    }
+
     #[inline]
     pub fn chain_id(&self) -> ChainId {
         self.pools[0].chain_id()
     }
 
-    /// Returns the mid price of the route
+    /// Returns whether both routes swap through the same tokens and fee tiers, regardless of the
+    /// state of their pools
+    ///
+    /// ## Arguments
+    ///
+    /// * `other`: The route to compare with
+    #[inline]
+    pub fn same_path<TInput2, TOutput2, TP2>(&self, other: &Route<TInput2, TOutput2, TP2>) -> bool
+    where
+        TInput2: BaseCurrency,
+        TOutput2: BaseCurrency,
+        TP2: TickDataProvider,
+    {
+        let (path, other_path) = (self.token_path(), other.token_path());
+        path.len() == other_path.len()
+            && path.iter().zip(&other_path).all(|(a, b)| a.equals(b))
+            && self
+                .pools
+                .iter()
+                .zip(&other.pools)
+                .all(|(a, b)| a.fee == b.fee)
+    }
+
+    /// Returns the mid price of the route, oriented as output per input regardless of the token
+    /// ordering of the pools
     #[inline]
     pub fn mid_price(&self) -> Result<Price<TInput, TOutput>, Error> {
         let mut price = self.pools[0].price_of(self.input.wrapped())?;
@@ -114,6 +164,79 @@ where
         self._mid_price = Some(mid_price.clone());
         Ok(mid_price)
     }
+
+    /// Returns a human readable description of the route, e.g. `WETH -0.3%-> USDC -0.05%-> DAI`.
+    /// Tokens without a symbol are shown by their truncated address.
+    #[cfg(feature = "fmt")]
+    #[inline]
+    pub fn describe(&self) -> alloc::string::String {
+        use alloc::format;
+        use alloy_primitives::aliases::U24;
+
+        let label = |token: &Token| match token.symbol() {
+            Some(symbol) => symbol.clone(),
+            None => {
+                let address = format!("{}", token.address());
+                format!("{}…{}", &address[..6], &address[address.len() - 4..])
+            }
+        };
+        let token_path = self.token_path();
+        let mut description = label(&token_path[0]);
+        for (pool, token) in self.pools.iter().zip(&token_path[1..]) {
+            let fee = U24::from(pool.fee).to::<u32>();
+            let fee = if fee % 10000 == 0 {
+                format!("{}", fee / 10000)
+            } else {
+                let fee = format!("{}.{:04}", fee / 10000, fee % 10000);
+                fee.trim_end_matches('0').into()
+            };
+            description += &format!(" -{fee}%-> {}", label(token));
+        }
+        description
+    }
+}
+
+impl<TP: TickDataProvider> Route<Token, Token, TP> {
+    /// Creates a route through the given tokens, resolving the pool of each hop.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tokens`: The tokens along the route, from input to output
+    /// * `fees`: The fee tier of each hop, one fewer than `tokens`
+    /// * `resolve_pool`: Resolves the pool of two adjacent tokens and a fee tier
+    #[inline]
+    pub fn from_tokens<F>(
+        tokens: &[Token],
+        fees: &[FeeAmount],
+        mut resolve_pool: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(&Token, &Token, FeeAmount) -> Result<Pool<TP>, Error>,
+    {
+        if tokens.len() < 2 {
+            return Err(Error::EmptyRoute);
+        }
+        if fees.len() != tokens.len() - 1 {
+            return Err(Error::InvalidRoute);
+        }
+        let pools = tokens
+            .windows(2)
+            .zip(fees)
+            .map(|(pair, fee)| {
+                let pool = resolve_pool(&pair[0], &pair[1], *fee)?;
+                if pool.involves_token(&pair[0]) && pool.involves_token(&pair[1]) {
+                    Ok(pool)
+                } else {
+                    Err(Error::InvalidRoute)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(
+            pools,
+            tokens[0].clone(),
+            tokens.last().unwrap().clone(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +258,114 @@ mod tests {
             assert_eq!(route.chain_id(), 1);
         }
 
+        #[test]
+        fn same_path_ignores_pool_state() {
+            let pool = |sqrt_ratio_x96, liquidity| {
+                Pool::new(
+                    TOKEN0.clone(),
+                    TOKEN1.clone(),
+                    FeeAmount::MEDIUM,
+                    sqrt_ratio_x96,
+                    liquidity,
+                )
+                .unwrap()
+            };
+            let route = Route::new(
+                vec![pool(SQRT_RATIO_X96, LIQUIDITY)],
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+            );
+            let other = Route::new(
+                vec![pool(encode_sqrt_ratio_x96(2, 1), 0)],
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+            );
+            assert!(route.same_path(&other));
+
+            let other_fee = Route::new(
+                vec![Pool::new(
+                    TOKEN0.clone(),
+                    TOKEN1.clone(),
+                    FeeAmount::LOW,
+                    SQRT_RATIO_X96,
+                    LIQUIDITY,
+                )
+                .unwrap()],
+                TOKEN0.clone(),
+                TOKEN1.clone(),
+            );
+            assert!(!route.same_path(&other_fee));
+            let reversed = Route::new(
+                vec![pool(SQRT_RATIO_X96, LIQUIDITY)],
+                TOKEN1.clone(),
+                TOKEN0.clone(),
+            );
+            assert!(!route.same_path(&reversed));
+        }
+
+        #[test]
+        fn from_tokens_builds_a_two_hop_route() {
+            let resolve_pool = |a: &Token, b: &Token, fee| {
+                Pool::new(a.clone(), b.clone(), fee, SQRT_RATIO_X96, LIQUIDITY)
+            };
+            let route = Route::from_tokens(
+                &[TOKEN0.clone(), TOKEN1.clone(), TOKEN2.clone()],
+                &[FeeAmount::MEDIUM, FeeAmount::LOW],
+                resolve_pool,
+            )
+            .unwrap();
+            assert_eq!(
+                route.token_path(),
+                vec![TOKEN0.clone(), TOKEN1.clone(), TOKEN2.clone()]
+            );
+            assert_eq!(route.pools[0].fee, FeeAmount::MEDIUM);
+            assert_eq!(route.pools[1].fee, FeeAmount::LOW);
+
+            assert!(matches!(
+                Route::from_tokens(&[TOKEN0.clone(), TOKEN1.clone()], &[], resolve_pool),
+                Err(Error::InvalidRoute)
+            ));
+            assert!(matches!(
+                Route::from_tokens(&[TOKEN0.clone()], &[], resolve_pool),
+                Err(Error::EmptyRoute)
+            ));
+        }
+
+        #[test]
+        #[cfg(feature = "fmt")]
+        fn describes_a_two_hop_route() {
+            let pool_1_2 = Pool::new(
+                TOKEN1.clone(),
+                TOKEN2.clone(),
+                FeeAmount::LOW,
+                encode_sqrt_ratio_x96(1, 1),
+                0,
+            )
+            .unwrap();
+            let route = Route::new(
+                vec![POOL_0_1.clone(), pool_1_2],
+                TOKEN0.clone(),
+                TOKEN2.clone(),
+            );
+            assert_eq!(route.describe(), "t0 -0.3%-> t1 -0.05%-> t2");
+        }
+
+        #[test]
+        #[cfg(feature = "fmt")]
+        fn describes_tokens_without_symbol_by_address() {
+            let token = uniswap_sdk_core::token!(1, "0000000000000000000000000000000000000003", 18);
+            let pool = Pool::new(
+                TOKEN1.clone(),
+                token.clone(),
+                FeeAmount::HIGH,
+                encode_sqrt_ratio_x96(1, 1),
+                0,
+            )
+            .unwrap();
+            let route = Route::new(vec![POOL_0_1.clone(), pool], TOKEN0.clone(), token);
+            assert_eq!(route.describe(), "t0 -0.3%-> t1 -1%-> 0x0000…0003");
+        }
+
         #[test]
         #[should_panic(expected = "INPUT")]
         fn fails_if_the_input_is_not_in_the_first_pool() {
@@ -170,6 +401,30 @@ mod tests {
             assert_eq!(route.output, *TOKEN0);
         }
 
+        #[test]
+        fn new_with_currencies_supports_ether_to_dai() {
+            let pool_weth_dai = Pool::new(
+                WETH.clone(),
+                DAI.clone(),
+                FeeAmount::MEDIUM,
+                encode_sqrt_ratio_x96(1, 1),
+                0,
+            )
+            .unwrap();
+            let route =
+                Route::new_with_currencies(vec![pool_weth_dai.clone()], ETHER.clone(), DAI.clone());
+            assert_eq!(route.pools, vec![pool_weth_dai]);
+            assert_eq!(route.input, *ETHER);
+            assert_eq!(route.output, *DAI);
+            assert_eq!(route.token_path(), vec![WETH.clone(), DAI.clone()]);
+        }
+
+        #[test]
+        #[should_panic(expected = "INPUT")]
+        fn new_with_currencies_fails_if_wrapped_input_is_not_in_the_first_pool() {
+            Route::new_with_currencies(vec![POOL_0_1.clone()], ETHER.clone(), TOKEN1.clone());
+        }
+
         #[test]
         fn supports_ether_output() {
             let route = Route::new(vec![POOL_0_WETH.clone()], TOKEN0.clone(), ETHER.clone());
@@ -248,6 +503,18 @@ mod tests {
             assert_eq!(price.quote_currency, *TOKEN0);
         }
 
+        #[test]
+        fn quotes_output_for_input_that_is_token1_of_the_first_pool() {
+            let route = Route::new(vec![POOL_0_1.clone()], TOKEN1.clone(), TOKEN0.clone());
+            let quote = route
+                .mid_price()
+                .unwrap()
+                .quote(&CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100).unwrap())
+                .unwrap();
+            assert_eq!(quote.currency, *TOKEN0);
+            assert_eq!(quote.quotient(), 500.into());
+        }
+
         #[test]
         fn correct_for_0_1_2() {
             let route = Route::new(
@@ -261,6 +528,30 @@ mod tests {
             assert_eq!(price.quote_currency, *TOKEN2);
         }
 
+        #[test]
+        fn is_the_product_of_the_hop_prices() {
+            let route = Route::new(
+                vec![POOL_1_2.clone(), POOL_0_1.clone()],
+                TOKEN2.clone(),
+                TOKEN0.clone(),
+            );
+            let pools = route.pool_path();
+            assert_eq!(pools.len(), 2);
+            // TOKEN2 is token1 of the first pool and TOKEN1 is token1 of the second
+            let hop_prices = pools[0]
+                .token1_price()
+                .multiply(&pools[1].token1_price())
+                .unwrap();
+            let price = route.mid_price().unwrap();
+            assert_eq!(price.as_fraction(), hop_prices.as_fraction());
+            assert_eq!(price.base_currency, *TOKEN2);
+            assert_eq!(price.quote_currency, *TOKEN0);
+            assert_eq!(
+                route.token_path(),
+                vec![TOKEN2.clone(), TOKEN1.clone(), TOKEN0.clone()]
+            );
+        }
+
         #[test]
         fn correct_for_2_1_0() {
             let route = Route::new(
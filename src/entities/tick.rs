@@ -7,6 +7,7 @@ use core::{
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tick<I = i32> {
     pub index: I,
     pub liquidity_gross: u128,
@@ -60,6 +61,20 @@ pub trait TickIndex:
 
     fn to_i24(self) -> I24;
 
+    /// Converts the tick to an [`I24`], saturating at the bounds of [`I24`] instead of panicking
+    #[inline]
+    fn to_i24_saturating(self) -> I24 {
+        let saturated = if self < Self::ZERO {
+            I24::MIN
+        } else {
+            I24::MAX
+        };
+        self.try_into()
+            .ok()
+            .and_then(|tick: i32| I24::try_from(tick).ok())
+            .unwrap_or(saturated)
+    }
+
     #[inline]
     fn compress(self, tick_spacing: Self) -> Self {
         assert!(tick_spacing > Self::ZERO, "TICK_SPACING");
@@ -29,6 +29,28 @@ pub trait TickDataProvider {
         lte: bool,
         tick_spacing: Self::Index,
     ) -> Result<(Self::Index, bool), Error>;
+
+    /// Check that every initialized tick held by the provider is a multiple of the tick spacing
+    ///
+    /// Providers that don't hold their ticks in memory have nothing to check and keep the default.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tick_spacing`: The tick spacing of the pool
+    #[inline]
+    fn validate_tick_spacing(&self, tick_spacing: Self::Index) -> Result<(), Error> {
+        let _ = tick_spacing;
+        Ok(())
+    }
+
+    /// Return the number of initialized ticks held by the provider
+    fn len(&self) -> usize;
+
+    /// Return whether the provider holds no initialized ticks
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Implements the [`TickDataProvider`] trait for any type that dereferences to a
@@ -54,11 +76,22 @@ where
         self.deref()
             .next_initialized_tick_within_one_word(tick, lte, tick_spacing)
     }
+
+    #[inline]
+    fn validate_tick_spacing(&self, tick_spacing: Self::Index) -> Result<(), Error> {
+        self.deref().validate_tick_spacing(tick_spacing)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.deref().len()
+    }
 }
 
 /// This tick data provider does not know how to fetch any tick data. It throws whenever it is
 /// required. Useful if you do not need to load tick data for your use case.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoTickDataProvider;
 
 impl TickDataProvider for NoTickDataProvider {
@@ -78,6 +111,11 @@ impl TickDataProvider for NoTickDataProvider {
     ) -> Result<(i32, bool), Error> {
         Err(Error::NoTickDataError)
     }
+
+    #[inline]
+    fn len(&self) -> usize {
+        0
+    }
 }
 
 #[cfg(all(feature = "std", test))]
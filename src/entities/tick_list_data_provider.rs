@@ -3,7 +3,19 @@ use alloc::vec::Vec;
 use derive_more::Deref;
 
 /// A data provider for ticks that is backed by an in-memory array of ticks.
+///
+/// With the `serde` feature, deserialization goes through the [`TryFrom`] implementation, which
+/// checks the ticks the way [`TickListDataProvider::new`] does except for the tick spacing, which
+/// is checked by the deserialization of the [`Pool`] holding the provider.
 #[derive(Clone, Debug, Default, PartialEq, Deref)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "Vec<Tick<I>>",
+        bound(deserialize = "I: TickIndex + serde::Deserialize<'de>")
+    )
+)]
 pub struct TickListDataProvider<I = i32>(Vec<Tick<I>>);
 
 impl<I: TickIndex> TickListDataProvider<I> {
@@ -14,10 +26,39 @@ impl<I: TickIndex> TickListDataProvider<I> {
     }
 }
 
+impl<I: TickIndex> TryFrom<Vec<Tick<I>>> for TickListDataProvider<I> {
+    type Error = Error;
+
+    /// Builds a provider from ticks of unknown origin, returning an error where
+    /// [`TickListDataProvider::new`] would panic
+    ///
+    /// The tick spacing is not known here, see [`TickDataProvider::validate_tick_spacing`].
+    #[inline]
+    fn try_from(ticks: Vec<Tick<I>>) -> Result<Self, Self::Error> {
+        if let Some(tick) = ticks
+            .iter()
+            .find(|tick| tick.index < I::from_i24(MIN_TICK) || tick.index > I::from_i24(MAX_TICK))
+        {
+            return Err(Error::InvalidTick(tick.index.to_i24_saturating()));
+        }
+        if ticks.windows(2).any(|pair| pair[0].index >= pair[1].index) {
+            return Err(TickListError::NotSorted.into());
+        }
+        let net = ticks.iter().try_fold(0_u128, |acc, tick| {
+            acc.checked_add_signed(tick.liquidity_net)
+        });
+        if net != Some(0) {
+            return Err(TickListError::NonZeroNet.into());
+        }
+        Ok(Self(ticks))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloc::vec;
+    use alloy_primitives::aliases::I24;
     use once_cell::sync::Lazy;
 
     static PROVIDER: Lazy<TickListDataProvider> =
@@ -62,4 +103,87 @@ mod tests {
         assert_eq!(tick.liquidity_net, -1);
         assert_eq!(tick.liquidity_gross, 1);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn round_trips_through_serde_for_swap_simulation() {
+        use crate::tests::*;
+        use uniswap_sdk_core::prelude::*;
+
+        let pool = make_pool(TOKEN0.clone(), TOKEN1.clone());
+        let json = serde_json::to_string(&pool.tick_data_provider).unwrap();
+        let tick_data_provider: TickListDataProvider = serde_json::from_str(&json).unwrap();
+        assert_eq!(tick_data_provider, pool.tick_data_provider);
+
+        let restored = Pool::new_with_tick_data_provider(
+            pool.token0.clone(),
+            pool.token1.clone(),
+            pool.fee,
+            pool.sqrt_ratio_x96,
+            pool.liquidity,
+            tick_data_provider,
+        )
+        .unwrap();
+        let input_amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap();
+        assert_eq!(
+            restored.get_output_amount(&input_amount, None).unwrap(),
+            pool.get_output_amount(&input_amount, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn try_from_rejects_invalid_tick_lists() {
+        assert_eq!(
+            TickListDataProvider::try_from(vec![Tick::new(-1, 1, 1), Tick::new(1, 1, -1)]).unwrap(),
+            *PROVIDER
+        );
+        assert!(matches!(
+            TickListDataProvider::try_from(vec![Tick::new(1, 1, -1), Tick::new(-1, 1, 1)]),
+            Err(Error::TickListError(TickListError::NotSorted))
+        ));
+        assert!(matches!(
+            TickListDataProvider::try_from(vec![Tick::new(-1, 1, 1), Tick::new(-1, 1, -1)]),
+            Err(Error::TickListError(TickListError::NotSorted))
+        ));
+        assert!(matches!(
+            TickListDataProvider::try_from(vec![Tick::new(-1, 1, -1), Tick::new(1, 1, 2)]),
+            Err(Error::TickListError(TickListError::NonZeroNet))
+        ));
+        let out_of_range = Tick {
+            index: MAX_TICK_I32 + 1,
+            liquidity_gross: 0,
+            liquidity_net: 0,
+        };
+        assert!(matches!(
+            TickListDataProvider::try_from(vec![out_of_range]),
+            Err(Error::InvalidTick(tick)) if tick == MAX_TICK + I24::ONE
+        ));
+    }
+
+    #[test]
+    fn validates_the_tick_spacing() {
+        assert!(PROVIDER.validate_tick_spacing(1).is_ok());
+        assert!(matches!(
+            PROVIDER.validate_tick_spacing(2),
+            Err(Error::InvalidTick(tick)) if tick == -I24::ONE
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_invalid_tick_lists() {
+        let json = serde_json::to_string(&vec![Tick::new(1, 1, -1), Tick::new(-1, 1, 1)]).unwrap();
+        assert!(serde_json::from_str::<TickListDataProvider>(&json).is_err());
+        let json = serde_json::to_string(&vec![Tick::new(-1, 1, -1), Tick::new(1, 1, 2)]).unwrap();
+        assert!(serde_json::from_str::<TickListDataProvider>(&json).is_err());
+    }
+
+    #[test]
+    fn counts_the_initialized_ticks() {
+        assert_eq!(TickDataProvider::len(&*PROVIDER), 2);
+        assert!(!TickDataProvider::is_empty(&*PROVIDER));
+        let empty = TickListDataProvider::<i32>::default();
+        assert_eq!(TickDataProvider::len(&empty), 0);
+        assert!(TickDataProvider::is_empty(&empty));
+    }
 }
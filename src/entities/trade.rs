@@ -73,6 +73,26 @@ pub struct BestTradeOptions {
     pub max_hops: Option<usize>,
 }
 
+/// The amounts of a trade together with its slippage bounds for the trade as a whole
+///
+/// The router is called with the bounds of each route instead, see [`Trade::per_route_min_out`]
+/// and [`Trade::per_route_max_in`], which can add up to slightly less after rounding.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SlippageSummary<TInput, TOutput>
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+{
+    /// The expected input amount of the trade
+    pub amount_in: CurrencyAmount<TInput>,
+    /// The expected output amount of the trade
+    pub amount_out: CurrencyAmount<TOutput>,
+    /// The minimum amount out, equal to `amount_out` for exact output trades
+    pub min_out: CurrencyAmount<TOutput>,
+    /// The maximum amount in, equal to `amount_in` for exact input trades
+    pub max_in: CurrencyAmount<TInput>,
+}
+
 /// Represents a swap through a route
 #[derive(Clone, PartialEq, Debug)]
 pub struct Swap<TInput, TOutput, TP>
@@ -132,6 +152,10 @@ where
 ///
 /// Does not account for slippage, i.e., changes in price environment that can occur between the
 /// time the trade is submitted and when it is executed.
+///
+/// A trade is assumed to be immutable after construction: the `_cached` methods memoize their
+/// results and never recompute them, so a trade whose swaps are modified must be reconstructed
+/// rather than reused.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Trade<TInput, TOutput, TP>
 where
@@ -232,6 +256,19 @@ where
         &self.swaps[0].route
     }
 
+    /// Returns the route carrying the largest share of the input amount, e.g. the dominant route
+    /// of a split trade. Ties resolve to the first such route.
+    #[inline]
+    pub fn best_route(&self) -> &Route<TInput, TOutput, TP> {
+        let mut best = &self.swaps[0];
+        for swap in &self.swaps[1..] {
+            if swap.input_amount.as_fraction() > best.input_amount.as_fraction() {
+                best = swap;
+            }
+        }
+        &best.route
+    }
+
     /// Returns the input currency of the swap
     #[inline]
     pub fn input_currency(&self) -> &TInput {
@@ -296,6 +333,84 @@ where
         Ok(output_amount)
     }
 
+    /// The output amount contributed by each route of the trade, in the order of [`Trade::swaps`].
+    /// The amounts sum to [`Trade::output_amount`].
+    #[inline]
+    pub fn outputs_per_route(&self) -> Vec<CurrencyAmount<TOutput>> {
+        self.swaps
+            .iter()
+            .map(|swap| swap.output_amount.clone())
+            .collect()
+    }
+
+    /// The minimum amount out of each route of the trade for the given slippage tolerance, in the
+    /// order of [`Trade::swaps`].
+    ///
+    /// Each amount is rounded down to a whole unit when encoded, so their rounded sum can fall
+    /// short of the rounded [`Trade::minimum_amount_out`] by up to one unit per route.
+    ///
+    /// ## Arguments
+    ///
+    /// * `slippage_tolerance`: The tolerance of unfavorable slippage from the execution price of
+    ///   this trade
+    #[inline]
+    pub fn per_route_min_out(
+        &self,
+        slippage_tolerance: Percent,
+    ) -> Result<Vec<CurrencyAmount<TOutput>>, Error> {
+        self.swaps
+            .iter()
+            .map(|swap| {
+                self.minimum_amount_out(
+                    slippage_tolerance.clone(),
+                    Some(swap.output_amount.clone()),
+                )
+            })
+            .collect()
+    }
+
+    /// The maximum amount in of each route of the trade for the given slippage tolerance, in the
+    /// order of [`Trade::swaps`].
+    ///
+    /// Each amount is rounded down to a whole unit when encoded, so their rounded sum can fall
+    /// short of the rounded [`Trade::maximum_amount_in`] by up to one unit per route.
+    ///
+    /// ## Arguments
+    ///
+    /// * `slippage_tolerance`: The tolerance of unfavorable slippage from the execution price of
+    ///   this trade
+    #[inline]
+    pub fn per_route_max_in(
+        &self,
+        slippage_tolerance: Percent,
+    ) -> Result<Vec<CurrencyAmount<TInput>>, Error> {
+        self.swaps
+            .iter()
+            .map(|swap| {
+                self.maximum_amount_in(slippage_tolerance.clone(), Some(swap.input_amount.clone()))
+            })
+            .collect()
+    }
+
+    /// The fee paid to the liquidity providers at each hop of the trade, in the input token of the
+    /// hop, in the order of [`Trade::swaps`] and of the pools of each route.
+    ///
+    /// The fees are computed by replaying each swap forward from its input amount.
+    #[inline]
+    pub fn fees_per_hop(&self) -> Result<Vec<(FeeAmount, CurrencyAmount<Token>)>, Error> {
+        let mut fees = Vec::new();
+        for swap in &self.swaps {
+            let mut token_amount: CurrencyAmount<Token> = swap.input_amount.wrapped_owned()?;
+            for pool in &swap.route.pools {
+                let (output_amount, fee_amount) =
+                    pool.get_output_amount_with_fee(&token_amount, None)?;
+                fees.push((pool.fee, fee_amount));
+                token_amount = output_amount;
+            }
+        }
+        Ok(fees)
+    }
+
     /// The price expressed in terms of output amount/input amount.
     #[inline]
     pub fn execution_price(&self) -> Result<Price<TInput, TOutput>, Error> {
@@ -473,12 +588,14 @@ where
             .map_err(|e| e.into())
     }
 
-    /// Return the execution price after accounting for slippage tolerance
+    /// Return the execution price after accounting for slippage tolerance, i.e. the least output
+    /// per unit of input that should be accepted
     ///
     /// ## Arguments
     ///
     /// * `slippage_tolerance`: The allowed tolerated slippage
     #[inline]
+    #[doc(alias = "price_floor")]
     pub fn worst_execution_price(
         &self,
         slippage_tolerance: Percent,
@@ -489,6 +606,28 @@ where
         ))
     }
 
+    /// Returns the expected amounts of the trade along with the worst-case bounds of the whole
+    /// trade for the given slippage tolerance, see [`SlippageSummary`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `slippage_tolerance`: The allowed tolerated slippage
+    #[inline]
+    pub fn slippage_summary(
+        &self,
+        slippage_tolerance: Percent,
+    ) -> Result<SlippageSummary<TInput, TOutput>, Error> {
+        let amount_in = self.input_amount()?;
+        let amount_out = self.output_amount()?;
+        Ok(SlippageSummary {
+            min_out: self
+                .minimum_amount_out(slippage_tolerance.clone(), Some(amount_out.clone()))?,
+            max_in: self.maximum_amount_in(slippage_tolerance, Some(amount_in.clone()))?,
+            amount_in,
+            amount_out,
+        })
+    }
+
     /// Return the execution price after accounting for slippage tolerance
     ///
     /// ## Arguments
@@ -533,6 +672,23 @@ where
         Self::from_route(route, amount_out, TradeType::ExactOutput)
     }
 
+    /// Returns the more advantageous of two trades with the same input and output currencies,
+    /// according to [`trade_comparator`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `a`: The first trade
+    /// * `b`: The second trade
+    #[inline]
+    #[must_use]
+    pub fn better_of(a: Self, b: Self) -> Self {
+        if trade_comparator(&a, &b) == Ordering::Greater {
+            b
+        } else {
+            a
+        }
+    }
+
     /// Constructs a trade by simulating swaps through the given route
     ///
     /// ## Arguments
@@ -597,6 +753,10 @@ where
     /// * `routes`: The routes to swap through and how much of the amount should be routed through
     ///   each
     /// * `trade_type`: Whether the trade is an exact input or exact output swap
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::CurrencyMismatch`] if the routes do not share the same output currency.
     #[inline]
     pub fn from_routes(
         routes: Vec<(
@@ -605,6 +765,15 @@ where
         )>,
         trade_type: TradeType,
     ) -> Result<Self, Error> {
+        if let Some((_, first)) = routes.first() {
+            let output_currency = first.output.wrapped();
+            if routes
+                .iter()
+                .any(|(_, route)| !route.output.wrapped().equals(output_currency))
+            {
+                return Err(Error::CurrencyMismatch);
+            }
+        }
         let mut populated_routes: Vec<Swap<TInput, TOutput, TP>> = Vec::with_capacity(routes.len());
         for (amount, route) in routes {
             let trade = Self::from_route(route, amount, trade_type)?;
@@ -612,6 +781,53 @@ where
         }
         Self::new(populated_routes, trade_type)
     }
+
+    /// Constructs a trade from an externally computed quote, e.g. from an aggregator, trusting the
+    /// quoted input and output amounts of each route
+    ///
+    /// ## Arguments
+    ///
+    /// * `routes`: The routes of the quote, each with its quoted input and output amounts
+    /// * `trade_type`: Whether the trade is an exact input or exact output swap
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::EmptyRoute`] if no route is given, and [`Error::CurrencyMismatch`] if a
+    /// quoted amount is not in the currency of its route or the routes do not share the same
+    /// input and output currencies.
+    #[inline]
+    pub fn from_aggregator_quote(
+        routes: Vec<(
+            Route<TInput, TOutput, TP>,
+            CurrencyAmount<TInput>,
+            CurrencyAmount<TOutput>,
+        )>,
+        trade_type: TradeType,
+    ) -> Result<Self, Error> {
+        let Some((first, _, _)) = routes.first() else {
+            return Err(Error::EmptyRoute);
+        };
+        let input_currency = first.input.wrapped();
+        let output_currency = first.output.wrapped();
+        if routes.iter().any(|(route, input_amount, output_amount)| {
+            !input_amount.currency.equals(&route.input)
+                || !output_amount.currency.equals(&route.output)
+                || !route.input.wrapped().equals(input_currency)
+                || !route.output.wrapped().equals(output_currency)
+        }) {
+            return Err(Error::CurrencyMismatch);
+        }
+        let swaps = routes
+            .into_iter()
+            .map(|(route, input_amount, output_amount)| {
+                Swap::new(route, input_amount, output_amount)
+            })
+            .collect();
+        let mut trade = Self::new(swaps, trade_type)?;
+        trade.input_amount_cached()?;
+        trade.output_amount_cached()?;
+        Ok(trade)
+    }
 }
 
 impl<TInput, TOutput, TP> Trade<TInput, TOutput, TP>
@@ -965,6 +1181,170 @@ mod tests {
     mod from_routes {
         use super::*;
 
+        #[test]
+        fn per_route_amounts_sum_to_within_one_unit_per_route() {
+            let trade = Trade::from_routes(
+                vec![
+                    (
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 3000).unwrap(),
+                        Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                    ),
+                    (
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 7000).unwrap(),
+                        Route::new(
+                            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                            TOKEN0.clone(),
+                            TOKEN2.clone(),
+                        ),
+                    ),
+                ],
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            let slippage_tolerance = Percent::new(5, 100);
+            let min_outs = trade.per_route_min_out(slippage_tolerance.clone()).unwrap();
+            assert_eq!(min_outs.len(), 2);
+            let total = min_outs[0].quotient() + min_outs[1].quotient();
+            let shortfall = trade
+                .minimum_amount_out(slippage_tolerance.clone(), None)
+                .unwrap()
+                .quotient()
+                - total;
+            assert!(shortfall >= BigInt::ZERO && shortfall <= BigInt::from(2));
+            let max_ins = trade.per_route_max_in(slippage_tolerance).unwrap();
+            assert_eq!(max_ins[0], trade.swaps[0].input_amount);
+            assert_eq!(max_ins[1], trade.swaps[1].input_amount);
+        }
+
+        #[test]
+        fn splits_an_order_across_two_pools_of_the_same_pair() {
+            let pool_0_1_low = v2_style_pool(
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 200000).unwrap(),
+                CurrencyAmount::from_raw_amount(TOKEN1.clone(), 200000).unwrap(),
+                Some(FeeAmount::LOW),
+            );
+            let amount_medium = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 4000).unwrap();
+            let amount_low = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 6000).unwrap();
+            let out_medium = POOL_0_1.get_output_amount(&amount_medium, None).unwrap();
+            let out_low = pool_0_1_low.get_output_amount(&amount_low, None).unwrap();
+
+            let trade = Trade::from_routes(
+                vec![
+                    (
+                        amount_medium,
+                        Route::new(vec![POOL_0_1.clone()], TOKEN0.clone(), TOKEN1.clone()),
+                    ),
+                    (
+                        amount_low,
+                        Route::new(vec![pool_0_1_low], TOKEN0.clone(), TOKEN1.clone()),
+                    ),
+                ],
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            assert_eq!(trade.swaps.len(), 2);
+            let input_amount = trade.input_amount().unwrap();
+            let output_amount = trade.output_amount().unwrap();
+            assert_eq!(
+                input_amount,
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap()
+            );
+            assert_eq!(output_amount, out_medium.add(&out_low).unwrap());
+            assert_eq!(
+                trade.execution_price().unwrap(),
+                Price::from_currency_amounts(input_amount, output_amount)
+            );
+        }
+
+        #[test]
+        fn errors_for_routes_with_different_output_currencies() {
+            let result = Trade::from_routes(
+                vec![
+                    (
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 3000).unwrap(),
+                        Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                    ),
+                    (
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 7000).unwrap(),
+                        Route::new(vec![POOL_0_1.clone()], TOKEN0.clone(), TOKEN1.clone()),
+                    ),
+                ],
+                TradeType::ExactInput,
+            );
+            assert!(matches!(result, Err(Error::CurrencyMismatch)));
+        }
+
+        #[test]
+        fn from_aggregator_quote_sums_the_quoted_route_outputs() {
+            let trade = Trade::from_aggregator_quote(
+                vec![
+                    (
+                        Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 3000).unwrap(),
+                        CurrencyAmount::from_raw_amount(TOKEN2.clone(), 2500).unwrap(),
+                    ),
+                    (
+                        Route::new(
+                            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                            TOKEN0.clone(),
+                            TOKEN2.clone(),
+                        ),
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 7000).unwrap(),
+                        CurrencyAmount::from_raw_amount(TOKEN2.clone(), 6000).unwrap(),
+                    ),
+                ],
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            assert_eq!(
+                trade.input_amount().unwrap(),
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap()
+            );
+            assert_eq!(
+                trade.output_amount().unwrap(),
+                CurrencyAmount::from_raw_amount(TOKEN2.clone(), 8500).unwrap()
+            );
+        }
+
+        #[test]
+        fn from_aggregator_quote_errors_for_mismatched_currencies() {
+            let result = Trade::from_aggregator_quote(
+                vec![(
+                    Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                    CurrencyAmount::from_raw_amount(TOKEN0.clone(), 3000).unwrap(),
+                    CurrencyAmount::from_raw_amount(TOKEN1.clone(), 2500).unwrap(),
+                )],
+                TradeType::ExactInput,
+            );
+            assert!(matches!(result, Err(Error::CurrencyMismatch)));
+
+            let result = Trade::from_aggregator_quote(
+                vec![
+                    (
+                        Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 3000).unwrap(),
+                        CurrencyAmount::from_raw_amount(TOKEN2.clone(), 2500).unwrap(),
+                    ),
+                    (
+                        Route::new(vec![POOL_0_1.clone()], TOKEN0.clone(), TOKEN1.clone()),
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 7000).unwrap(),
+                        CurrencyAmount::from_raw_amount(TOKEN1.clone(), 6000).unwrap(),
+                    ),
+                ],
+                TradeType::ExactInput,
+            );
+            assert!(matches!(result, Err(Error::CurrencyMismatch)));
+        }
+
+        #[test]
+        fn from_aggregator_quote_errors_without_routes() {
+            let result = Trade::<Token, Token, TickListDataProvider>::from_aggregator_quote(
+                vec![],
+                TradeType::ExactInput,
+            );
+            assert!(matches!(result, Err(Error::EmptyRoute)));
+        }
+
         #[test]
         fn can_be_constructed_with_ether_as_input_with_multiple_routes() {
             let trade = Trade::from_routes(
@@ -979,6 +1359,36 @@ mod tests {
             assert_eq!(trade.output_amount().unwrap().currency, TOKEN0.clone());
         }
 
+        #[test]
+        fn outputs_per_route_sum_to_the_output_amount() {
+            let trade = Trade::from_routes(
+                vec![
+                    (
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 3000).unwrap(),
+                        Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                    ),
+                    (
+                        CurrencyAmount::from_raw_amount(TOKEN0.clone(), 7000).unwrap(),
+                        Route::new(
+                            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                            TOKEN0.clone(),
+                            TOKEN2.clone(),
+                        ),
+                    ),
+                ],
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            let outputs = trade.outputs_per_route();
+            assert_eq!(outputs.len(), 2);
+            assert_eq!(outputs[0], trade.swaps[0].output_amount);
+            assert_eq!(outputs[1], trade.swaps[1].output_amount);
+            assert_eq!(
+                outputs[0].quotient() + outputs[1].quotient(),
+                trade.output_amount().unwrap().quotient()
+            );
+        }
+
         #[test]
         fn can_be_constructed_with_ether_as_input_for_exact_output_with_multiple_routes() {
             let trade = Trade::from_routes(
@@ -1378,6 +1788,14 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn is_strictly_worse_than_execution_price_if_nonzero() {
+                for trade in [EXACT_IN.clone(), EXACT_IN_MULTI_ROUTES.clone()] {
+                    let worst = trade.worst_execution_price(Percent::new(5, 100)).unwrap();
+                    assert!(worst.as_fraction() < trade.execution_price().unwrap().as_fraction());
+                }
+            }
+
             #[test]
             fn returns_exact_if_nonzero_with_multiple_routes() {
                 let trade = EXACT_IN_MULTI_ROUTES.clone();
@@ -1497,6 +1915,171 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fees_per_hop_itemizes_each_fee_tier() {
+        let pool_0_1 = v2_style_pool(
+            CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100000).unwrap(),
+            CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100000).unwrap(),
+            Some(FeeAmount::LOW),
+        );
+        let trade = Trade::exact_in(
+            Route::new(
+                vec![pool_0_1.clone(), POOL_1_2.clone()],
+                TOKEN0.clone(),
+                TOKEN2.clone(),
+            ),
+            CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap(),
+        )
+        .unwrap();
+        let fees = trade.fees_per_hop().unwrap();
+        assert_eq!(fees.len(), 2);
+
+        let (intermediate, fee0) = pool_0_1
+            .get_output_amount_with_fee(&trade.input_amount().unwrap(), None)
+            .unwrap();
+        let (_, fee1) = POOL_1_2
+            .get_output_amount_with_fee(&intermediate, None)
+            .unwrap();
+        assert_eq!(
+            fees,
+            vec![(FeeAmount::LOW, fee0), (FeeAmount::MEDIUM, fee1)]
+        );
+
+        // at least 0.05% of the input in TOKEN0, then 0.3% of the first output in TOKEN1
+        assert_eq!(fees[0].1.currency, TOKEN0.clone());
+        assert!(fees[0].1.quotient() >= BigInt::from(5));
+        assert_eq!(fees[1].1.currency, TOKEN1.clone());
+        assert!(
+            fees[1].1.quotient() * BigInt::from(1000) >= intermediate.quotient() * BigInt::from(3)
+        );
+    }
+
+    #[test]
+    fn worst_execution_price_is_below_execution_price() {
+        let exact_in = Trade::exact_in(
+            Route::new(
+                vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                TOKEN0.clone(),
+                TOKEN2.clone(),
+            ),
+            CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap(),
+        )
+        .unwrap();
+        let exact_out = Trade::exact_out(
+            Route::new(
+                vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                TOKEN0.clone(),
+                TOKEN2.clone(),
+            ),
+            CurrencyAmount::from_raw_amount(TOKEN2.clone(), 10000).unwrap(),
+        )
+        .unwrap();
+        for trade in [exact_in, exact_out] {
+            for slippage in [Percent::new(0, 100), Percent::new(5, 100)] {
+                let worst = trade.worst_execution_price(slippage).unwrap();
+                assert!(worst.as_fraction() <= trade.execution_price().unwrap().as_fraction());
+            }
+        }
+    }
+
+    #[test]
+    fn best_route_carries_the_largest_input_share() {
+        let trade = Trade::create_unchecked_trade_with_multiple_routes(
+            vec![
+                Swap::new(
+                    Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                    CurrencyAmount::from_raw_amount(TOKEN0.clone(), 30).unwrap(),
+                    CurrencyAmount::from_raw_amount(TOKEN2.clone(), 25).unwrap(),
+                ),
+                Swap::new(
+                    Route::new(
+                        vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                        TOKEN0.clone(),
+                        TOKEN2.clone(),
+                    ),
+                    CurrencyAmount::from_raw_amount(TOKEN0.clone(), 70).unwrap(),
+                    CurrencyAmount::from_raw_amount(TOKEN2.clone(), 60).unwrap(),
+                ),
+            ],
+            TradeType::ExactInput,
+        )
+        .unwrap();
+        assert_eq!(
+            trade.best_route().token_path(),
+            vec![TOKEN0.clone(), TOKEN1.clone(), TOKEN2.clone()]
+        );
+    }
+
+    #[test]
+    fn slippage_summary_bounds_the_unfixed_side() {
+        let route = Route::new(
+            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+            TOKEN0.clone(),
+            TOKEN2.clone(),
+        );
+        let exact_in = Trade::exact_in(
+            route.clone(),
+            CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            exact_in.slippage_summary(Percent::new(5, 100)).unwrap(),
+            SlippageSummary {
+                amount_in: CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap(),
+                amount_out: CurrencyAmount::from_raw_amount(TOKEN2.clone(), 7004).unwrap(),
+                min_out: CurrencyAmount::from_fractional_amount(TOKEN2.clone(), 700400, 105)
+                    .unwrap(),
+                max_in: CurrencyAmount::from_raw_amount(TOKEN0.clone(), 10000).unwrap(),
+            }
+        );
+
+        let exact_out = Trade::exact_out(
+            route,
+            CurrencyAmount::from_raw_amount(TOKEN2.clone(), 10000).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            exact_out.slippage_summary(Percent::new(5, 100)).unwrap(),
+            SlippageSummary {
+                amount_in: CurrencyAmount::from_raw_amount(TOKEN0.clone(), 15488).unwrap(),
+                amount_out: CurrencyAmount::from_raw_amount(TOKEN2.clone(), 10000).unwrap(),
+                min_out: CurrencyAmount::from_raw_amount(TOKEN2.clone(), 10000).unwrap(),
+                max_in: CurrencyAmount::from_fractional_amount(TOKEN0.clone(), 1626240, 100)
+                    .unwrap(),
+            }
+        );
+    }
+
+    mod better_of {
+        use super::*;
+
+        #[test]
+        fn picks_the_higher_output_trade() {
+            let amount_in = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap();
+            let direct = Trade::exact_in(
+                Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()),
+                amount_in.clone(),
+            )
+            .unwrap();
+            let multi_hop = Trade::exact_in(
+                Route::new(
+                    vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                    TOKEN0.clone(),
+                    TOKEN2.clone(),
+                ),
+                amount_in,
+            )
+            .unwrap();
+            let direct_output = direct.output_amount().unwrap();
+            assert!(direct_output.as_fraction() > multi_hop.output_amount().unwrap().as_fraction());
+
+            let better = Trade::better_of(multi_hop.clone(), direct.clone());
+            assert_eq!(better.output_amount().unwrap(), direct_output);
+            let better = Trade::better_of(direct, multi_hop);
+            assert_eq!(better.output_amount().unwrap(), direct_output);
+        }
+    }
+
     mod price_impact {
         use super::*;
 
@@ -1559,6 +2142,22 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn is_computed_once() {
+                let mut trade = EXACT_IN.clone();
+                let price_impact = |trade: &mut Trade<Token, Token, TickListDataProvider>| {
+                    trade
+                        .price_impact_cached()
+                        .unwrap()
+                        .to_significant(3, None)
+                        .unwrap()
+                };
+                assert_eq!(price_impact(&mut trade), "17.2");
+                // the cached value is returned without touching the swaps again
+                trade.swaps.clear();
+                assert_eq!(price_impact(&mut trade), "17.2");
+            }
+
             #[test]
             fn is_correct() {
                 assert_eq!(
@@ -2033,6 +2632,12 @@ mod tests {
                     trade.maximum_amount_in(Percent::new(5, 100), None).unwrap(),
                     CurrencyAmount::from_fractional_amount(TOKEN0.clone(), 1626240, 100).unwrap()
                 );
+                assert_eq!(
+                    trade
+                        .maximum_amount_in(Percent::new(100, 100), None)
+                        .unwrap(),
+                    CurrencyAmount::from_fractional_amount(TOKEN0.clone(), 3097600, 100).unwrap()
+                );
                 assert_eq!(
                     trade
                         .maximum_amount_in(Percent::new(200, 100), None)
@@ -2096,6 +2701,12 @@ mod tests {
                         .unwrap(),
                     CurrencyAmount::from_fractional_amount(TOKEN2.clone(), 700400, 105).unwrap()
                 );
+                assert_eq!(
+                    trade
+                        .minimum_amount_out(Percent::new(100, 100), None)
+                        .unwrap(),
+                    CurrencyAmount::from_fractional_amount(TOKEN2.clone(), 700400, 200).unwrap()
+                );
                 assert_eq!(
                     trade
                         .minimum_amount_out(Percent::new(200, 100), None)
@@ -24,13 +24,21 @@ pub enum Error {
     #[error("Invalid token")]
     InvalidToken,
 
+    /// Thrown when the percentage passed to [`FeeAmount::from_percent`] does not match a standard
+    /// fee tier.
+    #[error("Invalid fee")]
+    InvalidFee,
+
     /// Thrown when the tick passed to [`get_sqrt_ratio_at_tick`] is not between [`MIN_TICK`] and
-    /// [`MAX_TICK`].
+    /// [`MAX_TICK`], or when the tick passed to [`Pool::amount_in_to_tick`] cannot be reached by a
+    /// swap from the current price.
     #[error("Invalid tick: {0}")]
     InvalidTick(I24),
 
     /// Thrown when the price passed to [`get_tick_at_sqrt_ratio`] does not correspond to a price
-    /// between [`MIN_TICK`] and [`MAX_TICK`].
+    /// between [`MIN_TICK`] and [`MAX_TICK`], or when the price limit passed to
+    /// [`Pool::get_output_amount_with_limit`] or [`Pool::get_input_amount_with_limit`] is on the
+    /// wrong side of the current price.
     #[error("Invalid square root price: {0}")]
     InvalidSqrtPrice(U160),
 
@@ -49,6 +57,11 @@ pub enum Error {
     #[error("Overflow when casting to U160")]
     SafeCastToU160Overflow,
 
+    /// Thrown when the liquidity computed by [`get_liquidity_for_amounts`] or
+    /// [`Pool::full_range_amounts`] does not fit in a `u128`.
+    #[error("Overflow when casting to u128")]
+    SafeCastToU128Overflow,
+
     #[error("Overflow in price calculation")]
     PriceOverflow,
 
@@ -61,10 +74,51 @@ pub enum Error {
     #[error("{0}")]
     TickListError(#[from] TickListError),
 
-    #[cfg(feature = "extensions")]
+    /// Thrown when a route passed to [`encode_route_to_path`] has no pools, or when
+    /// [`Route::from_tokens`] is given fewer than two tokens, or when
+    /// [`Trade::from_aggregator_quote`] is given no route.
+    #[error("Empty route")]
+    EmptyRoute,
+
+    /// Thrown when the pools of a route passed to [`encode_route_to_path`] do not connect its
+    /// input to its output, or when the tokens and fees passed to [`Route::from_tokens`] do not
+    /// line up.
+    #[error("Invalid route")]
+    InvalidRoute,
+
+    /// Thrown when the routes passed to [`Trade::from_routes`] do not share the same output
+    /// currency, or when the quote passed to [`Trade::from_aggregator_quote`] does not line up
+    /// with its routes.
+    #[error("Currency mismatch")]
+    CurrencyMismatch,
+
+    /// Thrown when the lower tick of a range is not below its upper tick, such as the ticks passed
+    /// to [`Position::from_amounts`].
     #[error("Invalid tick range")]
     InvalidRange,
 
+    /// Thrown when a required token amount is zero, such as when the amounts passed to
+    /// [`Position::from_amounts`] are all zero or either amount passed to
+    /// [`Pool::initial_sqrt_ratio_from_amounts`] is zero.
+    #[error("Zero amount")]
+    ZeroAmount,
+
+    /// Thrown when [`Pool::full_range_amounts`] is not given exactly one of the two amounts, or
+    /// when the input amount passed to [`Pool::amount_out_within_tick`] does not fit in an
+    /// `int256`.
+    #[error("Invalid amounts")]
+    InvalidAmounts,
+
+    /// Thrown when an [`IncentiveKey`] has a zero reward token or refundee, or does not end after
+    /// it starts.
+    #[error("Invalid incentive key")]
+    InvalidIncentiveKey,
+
+    /// Thrown when the timestamp passed to [`compute_reward_amount`] is before the start of the
+    /// staking program.
+    #[error("Incentive not started")]
+    IncentiveNotStarted,
+
     #[cfg(feature = "extensions")]
     #[error("{0}")]
     ContractError(#[from] ContractError),
@@ -80,6 +134,16 @@ pub enum Error {
     #[cfg(feature = "extensions")]
     #[error("Invalid access list")]
     InvalidAccessList,
+
+    /// Thrown when a pool read from chain is not deployed or not yet initialized.
+    #[cfg(feature = "extensions")]
+    #[error("Pool not deployed or not initialized")]
+    UninitializedPool,
+
+    /// Thrown when an entity returned by a subgraph does not match the expected schema.
+    #[cfg(feature = "extensions")]
+    #[error("Invalid subgraph data")]
+    InvalidSubgraphData,
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, thiserror::Error)]
@@ -90,6 +154,10 @@ pub enum TickListError {
     AtOrAboveLargest,
     #[error("Not contained in tick list")]
     NotContained,
+    #[error("Ticks are not sorted")]
+    NotSorted,
+    #[error("Liquidity net does not sum to zero")]
+    NonZeroNet,
 }
 
 #[cfg(feature = "extensions")]
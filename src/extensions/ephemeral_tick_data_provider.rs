@@ -21,6 +21,20 @@ pub struct EphemeralTickDataProvider<I = I24> {
 }
 
 impl<I: TickIndex> EphemeralTickDataProvider<I> {
+    /// Fetches the initialized ticks of a pool within a range
+    ///
+    /// The ticks are read once, in a single `eth_call`, and the provider never goes back to the
+    /// chain. Pass the same `block_id` used to read the pool state, e.g. in
+    /// [`Pool::from_chain`], so that quotes are computed against a consistent snapshot. With
+    /// `None`, the latest block is read and may not match a pool state fetched separately.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool`: The address of the pool
+    /// * `provider`: The alloy provider
+    /// * `tick_lower`: The lower bound of the range, [`MIN_TICK`] if `None`
+    /// * `tick_upper`: The upper bound of the range, [`MAX_TICK`] if `None`
+    /// * `block_id`: Optional block number to query
     #[inline]
     pub async fn new<N, P>(
         pool: Address,
@@ -19,6 +19,8 @@ pub struct EphemeralTickMapDataProvider<I = I24> {
 }
 
 impl<I: TickIndex> EphemeralTickMapDataProvider<I> {
+    /// Fetches the initialized ticks of a pool within a range into a [`TickMap`], see
+    /// [`EphemeralTickDataProvider::new`] for the arguments and the choice of `block_id`
     #[inline]
     pub async fn new<N, P>(
         pool: Address,
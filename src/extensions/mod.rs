@@ -5,9 +5,11 @@ mod ephemeral_tick_map_data_provider;
 mod pool;
 mod position;
 mod price_tick_conversions;
+mod staker;
 mod state_overrides;
 mod tick_bit_map;
 mod tick_map;
+mod watch;
 
 pub use ephemeral_tick_data_provider::EphemeralTickDataProvider;
 pub use ephemeral_tick_map_data_provider::EphemeralTickMapDataProvider;
@@ -17,5 +19,6 @@ pub use price_tick_conversions::*;
 pub use state_overrides::*;
 pub use tick_bit_map::*;
 pub use tick_map::*;
+pub use watch::*;
 
 pub use uniswap_lens as lens;
@@ -17,7 +17,10 @@ use uniswap_lens::{
     },
     pool_lens,
 };
-use uniswap_sdk_core::{prelude::Token, token};
+use uniswap_sdk_core::{
+    prelude::{BaseCurrency, Token},
+    token,
+};
 
 #[inline]
 pub fn get_pool_contract<N, P>(
@@ -114,6 +117,105 @@ impl Pool {
     }
 }
 
+impl Pool {
+    /// Get a [`Pool`] struct of the given tokens and fee tier from chain, reading `slot0` and
+    /// `liquidity` through a single multicall
+    ///
+    /// ## Arguments
+    ///
+    /// * `provider`: The alloy provider
+    /// * `token_a`: One of the tokens in the pool
+    /// * `token_b`: The other token in the pool
+    /// * `fee`: Fee tier of the pool
+    /// * `block_id`: Optional block number to query.
+    #[inline]
+    pub async fn from_chain<N, P>(
+        provider: P,
+        token_a: Token,
+        token_b: Token,
+        fee: FeeAmount,
+        block_id: Option<BlockId>,
+    ) -> Result<Self, Error>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        Self::from_chain_with_tick_data_provider(
+            provider,
+            token_a,
+            token_b,
+            fee,
+            NoTickDataProvider,
+            block_id,
+        )
+        .await
+    }
+}
+
+impl<TP: TickDataProvider> Pool<TP> {
+    /// Same as [`Pool::from_chain`], but with a pre-built tick data provider
+    ///
+    /// ## Arguments
+    ///
+    /// * `provider`: The alloy provider
+    /// * `token_a`: One of the tokens in the pool
+    /// * `token_b`: The other token in the pool
+    /// * `fee`: Fee tier of the pool
+    /// * `tick_data_provider`: The tick data provider of the pool
+    /// * `block_id`: Optional block number to query.
+    #[inline]
+    pub async fn from_chain_with_tick_data_provider<N, P>(
+        provider: P,
+        token_a: Token,
+        token_b: Token,
+        fee: FeeAmount,
+        tick_data_provider: TP,
+        block_id: Option<BlockId>,
+    ) -> Result<Self, Error>
+    where
+        N: Network,
+        P: Provider<N>,
+    {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
+        let factory = chain_addresses(token_a.chain_id())
+            .map_or(FACTORY_ADDRESS, |addresses| addresses.factory);
+        let pool_contract = get_pool_contract(
+            factory,
+            token_a.address(),
+            token_b.address(),
+            fee,
+            provider.root(),
+        );
+        // calls to an address without code return no data, which fails to decode
+        if provider
+            .get_code_at(*pool_contract.address())
+            .block_id(block_id)
+            .await?
+            .is_empty()
+        {
+            return Err(Error::UninitializedPool);
+        }
+        let (slot_0, liquidity) = provider
+            .multicall()
+            .add(pool_contract.slot0())
+            .add(pool_contract.liquidity())
+            .block(block_id)
+            .aggregate()
+            .await?;
+        if slot_0.sqrtPriceX96.is_zero() {
+            return Err(Error::UninitializedPool);
+        }
+        Self::new_with_tick_data_provider(
+            token_a,
+            token_b,
+            fee,
+            slot_0.sqrtPriceX96,
+            liquidity._0,
+            tick_data_provider,
+        )
+    }
+}
+
 impl<I: TickIndex> Pool<EphemeralTickMapDataProvider<I>> {
     /// Get a [`Pool`] struct with tick data provider from pool key
     ///
@@ -320,7 +422,11 @@ where
 mod tests {
     use super::*;
     use crate::tests::*;
-    use alloy_primitives::address;
+    use alloy::rpc::types::{Filter, TransactionRequest};
+    use alloy_primitives::{address, U160};
+    use alloy_sol_types::SolEvent;
+    use uniswap_lens::bindings::iuniswapv3pool::IUniswapV3Pool;
+    use uniswap_sdk_core::prelude::{CurrencyAmount, ToBig, TradeType};
 
     async fn pool() -> Pool {
         Pool::from_pool_key(
@@ -345,6 +451,132 @@ mod tests {
         assert_eq!(pool.liquidity, 786352807736110014);
     }
 
+    #[tokio::test]
+    async fn test_from_chain() {
+        let pool = pool().await;
+        let from_chain = Pool::from_chain(
+            PROVIDER.clone(),
+            pool.token0.clone(),
+            pool.token1.clone(),
+            FeeAmount::LOW,
+            *BLOCK_ID,
+        )
+        .await
+        .unwrap();
+        assert_eq!(from_chain, pool);
+
+        let result = Pool::from_chain(
+            PROVIDER.clone(),
+            pool.token0,
+            pool.token1,
+            FeeAmount::CUSTOM(1234),
+            *BLOCK_ID,
+        )
+        .await;
+        assert!(matches!(result, Err(Error::UninitializedPool)));
+    }
+
+    #[tokio::test]
+    async fn test_get_output_amount_mut_matches_the_quoter() {
+        let mut pool = Pool::from_pool_key_with_tick_data_provider(
+            1,
+            FACTORY_ADDRESS,
+            address!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599"),
+            address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+            FeeAmount::LOW,
+            PROVIDER.clone(),
+            *BLOCK_ID,
+        )
+        .await
+        .unwrap();
+        // 100 WBTC moves the price leftward across several initialized ticks
+        let amount_in =
+            CurrencyAmount::from_raw_amount(pool.token0.clone(), 10_000_000_000_u64).unwrap();
+        let route = Route::new(vec![pool.clone()], pool.token0.clone(), pool.token1.clone());
+        let params = quote_call_parameters(
+            &route,
+            &amount_in,
+            TradeType::ExactInput,
+            Some(QuoteOptions {
+                sqrt_price_limit_x96: U160::ZERO,
+                use_quoter_v2: true,
+            }),
+        )
+        .unwrap();
+        let tx = TransactionRequest::default()
+            .to(address!("61fFE014bA17989E743c5F6cB21bF9697530B21e"))
+            .input(params.calldata.into());
+        let quote = decode_quote_exact_input_single_v2(
+            PROVIDER.call(tx).block(BLOCK_ID.unwrap()).await.unwrap(),
+        )
+        .unwrap();
+
+        let tick_before = pool.tick_current;
+        let amount_out = pool.get_output_amount_mut(&amount_in, None).unwrap();
+        assert_eq!(amount_out.quotient(), quote.amount_out.to_big_int());
+        assert_eq!(pool.sqrt_ratio_x96, quote.sqrt_price_x96_after);
+        assert!(pool.tick_current < tick_before);
+        // `apply_swap` rejects a tick that the pool contract would not store for the price
+        pool.clone()
+            .apply_swap(
+                quote.sqrt_price_x96_after,
+                pool.liquidity,
+                pool.tick_current,
+            )
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_swap_deltas_match_an_on_chain_swap_event() {
+        let pool_address = pool().await.address(None, None);
+        let filter = Filter::new()
+            .address(pool_address)
+            .from_block(17000000)
+            .to_block(17000100);
+        let logs = PROVIDER.get_logs(&filter).await.unwrap();
+        // the first swap that is also the first event of the pool in its block, so that the pool
+        // state at the end of the previous block is the state the swap started from
+        let log = logs
+            .iter()
+            .enumerate()
+            .find(|(i, log)| {
+                log.topic0() == Some(&IUniswapV3Pool::Swap::SIGNATURE_HASH)
+                    && (*i == 0 || logs[i - 1].block_number != log.block_number)
+            })
+            .map(|(_, log)| log)
+            .unwrap();
+        let event = IUniswapV3Pool::Swap::decode_log_data(log.data(), true).unwrap();
+        let pool = Pool::from_pool_key_with_tick_data_provider(
+            1,
+            FACTORY_ADDRESS,
+            address!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599"),
+            address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+            FeeAmount::LOW,
+            PROVIDER.clone(),
+            Some(BlockId::from(log.block_number.unwrap() - 1)),
+        )
+        .await
+        .unwrap();
+
+        // the swap was either exact input of the amount paid in or exact output of the amount
+        // paid out, and replaying it must reproduce the signed amounts of the event
+        let zero_for_one = event.amount0.is_positive();
+        let (amount_in, amount_out) = if zero_for_one {
+            (event.amount0, event.amount1)
+        } else {
+            (event.amount1, event.amount0)
+        };
+        assert!(amount_in.is_positive() && amount_out.is_negative());
+        let expected = (event.amount0, event.amount1);
+        let exact_input = pool.swap_deltas(amount_in, zero_for_one, None).unwrap();
+        let exact_output = pool.swap_deltas(amount_out, zero_for_one, None).unwrap();
+        assert!(
+            exact_input == expected || exact_output == expected,
+            "swap {:?}: {expected:?} is neither {exact_input:?} nor {exact_output:?}",
+            log.transaction_hash
+        );
+    }
+
     #[tokio::test]
     async fn test_get_liquidity_array_for_pool() {
         let pool = pool().await;
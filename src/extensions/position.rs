@@ -377,6 +377,11 @@ where
 /// * `position`: Position info before rebalance.
 /// * `new_tick_lower`: The new lower tick.
 /// * `new_tick_upper`: The new upper tick.
+///
+/// ## Errors
+///
+/// Returns [`Error::ZeroAmount`] if the position is worth nothing at the current price, e.g. an
+/// empty position, since no liquidity can be minted from zero amounts.
 #[inline]
 pub fn get_rebalanced_position<TP>(
     mut position: Position<TP>,
@@ -447,6 +452,11 @@ where
 /// * `new_price`: The new pool price
 /// * `new_tick_lower`: The new lower tick.
 /// * `new_tick_upper`: The new upper tick.
+///
+/// ## Errors
+///
+/// Returns [`Error::ZeroAmount`] if the position is worth nothing at the new price, like
+/// [`get_rebalanced_position`].
 #[inline]
 pub fn get_rebalanced_position_at_price<TP>(
     position: Position<TP>,
@@ -467,7 +477,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::PROVIDER;
+    use crate::tests::{make_pool, PROVIDER, TOKEN0, TOKEN1};
     use alloy_primitives::{address, uint};
     use fastnum::decimal::Context;
 
@@ -578,6 +588,15 @@ mod tests {
         assert!(position.liquidity - reverted_position.liquidity < position.liquidity / 1000000);
     }
 
+    #[test]
+    fn test_get_rebalanced_position_of_empty_position() {
+        let position = Position::new(make_pool(TOKEN0.clone(), TOKEN1.clone()), 0, -60, 60);
+        assert!(matches!(
+            get_rebalanced_position(position, -120, 120),
+            Err(Error::ZeroAmount)
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_position_at_price() {
         let position = get_position(1, NPM, uint!(4_U256), PROVIDER.clone(), BLOCK_ID)
@@ -0,0 +1,105 @@
+//! ## Staker Extension
+//! This module provides a function to create an [`IncentiveKey`] from an incentive entity returned
+//! by the [Uniswap V3 staker subgraph](https://github.com/Uniswap/v3-staker-subgraph).
+
+use crate::prelude::*;
+use alloy_primitives::{Address, U256};
+use core::str::FromStr;
+use serde_json::Value;
+
+/// Reads a field of a subgraph entity that is either a string or a nested entity with an `id`.
+fn subgraph_field<'a>(entity: &'a Value, field: &str) -> Result<&'a str, Error> {
+    match entity.get(field) {
+        Some(Value::String(s)) => Ok(s),
+        Some(Value::Object(object)) => object
+            .get("id")
+            .and_then(Value::as_str)
+            .ok_or(Error::InvalidSubgraphData),
+        _ => Err(Error::InvalidSubgraphData),
+    }
+}
+
+fn subgraph_address(entity: &Value, field: &str) -> Result<Address, Error> {
+    Address::from_str(subgraph_field(entity, field)?).map_err(|_| Error::InvalidSubgraphData)
+}
+
+fn subgraph_uint(entity: &Value, field: &str) -> Result<U256, Error> {
+    match entity.get(field) {
+        Some(Value::Number(n)) => n.as_u64().map(U256::from),
+        Some(Value::String(s)) => U256::from_str(s).ok(),
+        _ => None,
+    }
+    .ok_or(Error::InvalidSubgraphData)
+}
+
+impl<TP: TickDataProvider> IncentiveKey<TP> {
+    /// Parses an `Incentive` entity of the staker subgraph.
+    ///
+    /// `rewardToken`, `pool` and `refundee` may be given either as hex strings or as nested
+    /// entities with an `id`, and `startTime`/`endTime` as strings or numbers.
+    ///
+    /// ## Arguments
+    ///
+    /// * `json`: The JSON of the incentive entity
+    /// * `pool_resolver`: Resolves the pool from its address
+    #[inline]
+    pub fn from_subgraph_json<F>(json: &str, pool_resolver: F) -> Result<Self, Error>
+    where
+        F: FnOnce(Address) -> Result<Pool<TP>, Error>,
+    {
+        let entity: Value = serde_json::from_str(json).map_err(|_| Error::InvalidSubgraphData)?;
+        Ok(Self {
+            reward_token: subgraph_address(&entity, "rewardToken")?,
+            pool: pool_resolver(subgraph_address(&entity, "pool")?)?,
+            start_time: subgraph_uint(&entity, "startTime")?,
+            end_time: subgraph_uint(&entity, "endTime")?,
+            refundee: subgraph_address(&entity, "refundee")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+    use alloc::format;
+    use alloy_primitives::address;
+
+    #[test]
+    fn from_subgraph_json() {
+        let pool_address = POOL_0_1.address(None, None);
+        let json = format!(
+            r#"{{
+                "id": "0x01",
+                "rewardToken": {{ "id": "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984" }},
+                "pool": {{ "id": "{pool_address}" }},
+                "startTime": "100",
+                "endTime": 200,
+                "refundee": "0x0000000000000000000000000000000000000001"
+            }}"#
+        );
+        let incentive_key = IncentiveKey::from_subgraph_json(&json, |address| {
+            assert_eq!(address, pool_address);
+            Ok(POOL_0_1.clone())
+        })
+        .unwrap();
+        assert_eq!(incentive_key.pool, *POOL_0_1);
+        assert_eq!(
+            encode_incentive_key(&incentive_key),
+            IUniswapV3Staker::IncentiveKey {
+                rewardToken: address!("1f9840a85d5aF5bf1D1762F925BDADdC4201F984"),
+                pool: pool_address,
+                startTime: U256::from(100),
+                endTime: U256::from(200),
+                refundee: address!("0000000000000000000000000000000000000001"),
+            }
+        );
+    }
+
+    #[test]
+    fn from_subgraph_json_rejects_missing_fields() {
+        let result =
+            IncentiveKey::from_subgraph_json(r#"{ "startTime": "100" }"#, |_| Ok(POOL_0_1.clone()));
+        assert!(matches!(result, Err(Error::InvalidSubgraphData)));
+    }
+}
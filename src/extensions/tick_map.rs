@@ -31,6 +31,89 @@ impl<I: TickIndex> TickMap<I> {
             tick_spacing,
         }
     }
+
+    /// Applies the liquidity delta of a `Mint` or `Burn` event to the ticks bounding the position,
+    /// mirroring `UniswapV3Pool._updatePosition`. Ticks are initialized when they gain liquidity
+    /// and removed once their gross liquidity reaches zero.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tick_lower`: The lower tick of the position
+    /// * `tick_upper`: The upper tick of the position
+    /// * `liquidity_delta`: The liquidity added by a mint, or the negated liquidity removed by a
+    ///   burn
+    #[inline]
+    pub fn apply_liquidity_delta(
+        &mut self,
+        tick_lower: I,
+        tick_upper: I,
+        liquidity_delta: i128,
+    ) -> Result<(), Error> {
+        if tick_lower >= tick_upper {
+            return Err(Error::InvalidRange);
+        }
+        for tick in [tick_lower, tick_upper] {
+            if !(tick % self.tick_spacing).is_zero()
+                || tick < I::from_i24(MIN_TICK)
+                || tick > I::from_i24(MAX_TICK)
+            {
+                return Err(Error::InvalidTick(tick.to_i24()));
+            }
+        }
+        if liquidity_delta == 0 {
+            return Ok(());
+        }
+
+        // compute both ticks before updating either, so that an overflow leaves the map untouched
+        let lower = self.updated_tick(tick_lower, liquidity_delta, false)?;
+        let upper = self.updated_tick(tick_upper, liquidity_delta, true)?;
+        for tick in [lower, upper] {
+            let initialized = self.inner.contains_key(&tick.index);
+            if tick.liquidity_gross == 0 {
+                if initialized {
+                    self.inner.remove(&tick.index);
+                    self.flip_tick(tick.index);
+                }
+            } else {
+                if !initialized {
+                    self.flip_tick(tick.index);
+                }
+                self.inner.insert(tick.index, tick);
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn updated_tick(&self, index: I, liquidity_delta: i128, upper: bool) -> Result<Tick<I>, Error> {
+        let tick = self.inner.get(&index).copied().unwrap_or(Tick {
+            index,
+            liquidity_gross: 0,
+            liquidity_net: 0,
+        });
+        let liquidity_net = if upper {
+            tick.liquidity_net.checked_sub(liquidity_delta)
+        } else {
+            tick.liquidity_net.checked_add(liquidity_delta)
+        }
+        .ok_or(Error::AddDeltaOverflow)?;
+        Ok(Tick {
+            index,
+            liquidity_gross: add_delta(tick.liquidity_gross, liquidity_delta)?,
+            liquidity_net,
+        })
+    }
+
+    #[inline]
+    fn flip_tick(&mut self, index: I) {
+        let (word_pos, bit_pos) = index.compress(self.tick_spacing).position();
+        let word = self.bitmap.get_word(word_pos) ^ (uint!(1_U256) << bit_pos);
+        if word.is_zero() {
+            self.bitmap.remove(&word_pos);
+        } else {
+            self.bitmap.insert(word_pos, word);
+        }
+    }
 }
 
 impl<I: TickIndex> TickDataProvider for TickMap<I> {
@@ -53,4 +136,64 @@ impl<I: TickIndex> TickDataProvider for TickMap<I> {
         self.bitmap
             .next_initialized_tick_within_one_word(tick, lte, tick_spacing)
     }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn counts_the_initialized_ticks() {
+        let tick_map = TickMap::new(vec![Tick::new(-1, 1, 1), Tick::new(1, 1, -1)], 1);
+        assert_eq!(tick_map.len(), 2);
+        assert!(!tick_map.is_empty());
+        let empty = TickMap::<i32>::new(vec![], 1);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn apply_liquidity_delta_mint_then_burn() {
+        let original = TickMap::new(vec![Tick::new(-20, 5, 5), Tick::new(20, 5, -5)], 10);
+        let mut tick_map = original.clone();
+
+        tick_map.apply_liquidity_delta(-20, 40, 3).unwrap();
+        assert_eq!(tick_map.len(), 3);
+        assert_eq!(tick_map.get_tick(-20).unwrap(), &Tick::new(-20, 8, 8));
+        assert_eq!(tick_map.get_tick(40).unwrap(), &Tick::new(40, 3, -3));
+        assert_eq!(
+            tick_map
+                .next_initialized_tick_within_one_word(20, false, 10)
+                .unwrap(),
+            (40, true)
+        );
+
+        tick_map.apply_liquidity_delta(-20, 40, -3).unwrap();
+        assert_eq!(tick_map.inner, original.inner);
+        assert_eq!(tick_map.bitmap, original.bitmap);
+    }
+
+    #[test]
+    fn apply_liquidity_delta_rejects_invalid_ranges() {
+        let mut tick_map = TickMap::new(vec![Tick::new(-20, 5, 5), Tick::new(20, 5, -5)], 10);
+        assert!(matches!(
+            tick_map.apply_liquidity_delta(20, -20, 1),
+            Err(Error::InvalidRange)
+        ));
+        assert!(matches!(
+            tick_map.apply_liquidity_delta(-20, 25, 1),
+            Err(Error::InvalidTick(_))
+        ));
+        assert!(matches!(
+            tick_map.apply_liquidity_delta(-20, 20, -6),
+            Err(Error::AddDeltaOverflow)
+        ));
+        assert_eq!(tick_map.get_tick(-20).unwrap(), &Tick::new(-20, 5, 5));
+    }
 }
@@ -0,0 +1,209 @@
+//! ## Pool Watcher
+//! This module provides [`watch_pool`] to keep an in-memory [`Pool`] in sync with the chain by
+//! reading its state on every new block.
+
+use crate::prelude::*;
+use alloy::{eips::BlockId, network::Network, providers::Provider};
+use alloy_primitives::Address;
+use futures_util::{stream, Stream, StreamExt};
+use uniswap_lens::bindings::iuniswapv3pool::IUniswapV3Pool::IUniswapV3PoolInstance;
+
+/// Watches a pool and returns a stream of its state at every new block
+///
+/// The tokens and fee tier of the pool are read once, then `slot0` and `liquidity` are read through
+/// a single multicall pinned to each new block hash, as reported by the block filter of the
+/// provider.
+///
+/// ## Arguments
+///
+/// * `provider`: The alloy provider
+/// * `pool_address`: The address of the pool to watch
+///
+/// ## Returns
+///
+/// A stream yielding the pool at each new block, or the error encountered while reading it
+#[inline]
+pub async fn watch_pool<N, P>(
+    provider: P,
+    pool_address: Address,
+) -> Result<impl Stream<Item = Result<Pool, Error>>, Error>
+where
+    N: Network,
+    P: Provider<N> + Clone,
+{
+    let pool_contract = IUniswapV3PoolInstance::new(pool_address, provider.root());
+    let (factory, token0, token1, fee) = provider
+        .multicall()
+        .add(pool_contract.factory())
+        .add(pool_contract.token0())
+        .add(pool_contract.token1())
+        .add(pool_contract.fee())
+        .aggregate()
+        .await?;
+    let chain_id = provider.get_chain_id().await?;
+    let Pool {
+        token0,
+        token1,
+        fee,
+        ..
+    } = Pool::from_pool_key(
+        chain_id,
+        factory._0,
+        token0._0,
+        token1._0,
+        fee._0.into(),
+        provider.clone(),
+        None,
+    )
+    .await?;
+
+    let poller = provider.watch_blocks().await?;
+    Ok(poller
+        .into_stream()
+        .flat_map(stream::iter)
+        .then(move |block_hash| {
+            let provider = provider.clone();
+            let (token0, token1) = (token0.clone(), token1.clone());
+            async move {
+                let pool_contract = IUniswapV3PoolInstance::new(pool_address, provider.root());
+                let (slot_0, liquidity) = provider
+                    .multicall()
+                    .add(pool_contract.slot0())
+                    .add(pool_contract.liquidity())
+                    .block(BlockId::hash(block_hash))
+                    .aggregate()
+                    .await?;
+                Pool::new(token0, token1, fee, slot_0.sqrtPriceX96, liquidity._0)
+            }
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+    use alloc::{string::String, vec::Vec};
+    use alloy::{providers::ProviderBuilder, transports::mock::Asserter};
+    use alloy_primitives::{address, Bytes, B256, U160, U256, U64};
+    use alloy_sol_types::SolValue;
+    use core::pin::pin;
+    use uniswap_sdk_core::token;
+
+    /// Encodes the return data of a `Multicall3.aggregate` call
+    fn aggregate(return_data: Vec<Vec<u8>>) -> Bytes {
+        let return_data: Vec<Bytes> = return_data.into_iter().map(Bytes::from).collect();
+        (U256::from(17000000), return_data)
+            .abi_encode_params()
+            .into()
+    }
+
+    /// Encodes the return data of `slot0` and `liquidity`
+    fn pool_state(sqrt_price_x96: U160, liquidity: u128) -> Vec<Vec<u8>> {
+        vec![
+            (
+                U256::from(sqrt_price_x96),
+                U256::ZERO,
+                U256::ZERO,
+                U256::ZERO,
+                U256::ZERO,
+                U256::ZERO,
+                true,
+            )
+                .abi_encode_params(),
+            (U256::from(liquidity),).abi_encode_params(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_watch_pool_with_mocked_provider() {
+        let token0 = address!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599");
+        let token1 = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        let sqrt_price_x96 = encode_sqrt_ratio_x96(1, 1);
+        let asserter = Asserter::new();
+        // factory, tokens and fee tier of the pool
+        asserter.push_success(&aggregate(vec![
+            (FACTORY_ADDRESS,).abi_encode_params(),
+            (token0,).abi_encode_params(),
+            (token1,).abi_encode_params(),
+            (U256::from(500),).abi_encode_params(),
+        ]));
+        asserter.push_success(&U64::from(1));
+        // `Pool::from_pool_key`
+        let mut from_pool_key = pool_state(sqrt_price_x96, 1);
+        from_pool_key.extend([
+            (U256::from(8),).abi_encode_params(),
+            (String::from("Wrapped BTC"),).abi_encode_params(),
+            (String::from("WBTC"),).abi_encode_params(),
+            (U256::from(18),).abi_encode_params(),
+            (String::from("Wrapped Ether"),).abi_encode_params(),
+            (String::from("WETH"),).abi_encode_params(),
+        ]);
+        asserter.push_success(&aggregate(from_pool_key));
+        // block filter id and two new blocks
+        asserter.push_success(&U256::from(1));
+        asserter.push_success(&vec![B256::repeat_byte(1), B256::repeat_byte(2)]);
+        asserter.push_success(&aggregate(pool_state(sqrt_price_x96, 1000)));
+        asserter.push_success(&aggregate(pool_state(sqrt_price_x96, 2000)));
+        let provider = ProviderBuilder::new().on_mocked_client(asserter);
+
+        let pool_address =
+            compute_pool_address(FACTORY_ADDRESS, token0, token1, FeeAmount::LOW, None, None);
+        let pools = watch_pool(provider, pool_address)
+            .await
+            .unwrap()
+            .take(2)
+            .collect::<Vec<_>>()
+            .await;
+        let token0 = token!(1, token0, 8, "WBTC", "Wrapped BTC");
+        let token1 = token!(1, token1, 18, "WETH", "Wrapped Ether");
+        let expected: Vec<Pool> = [1000, 2000]
+            .into_iter()
+            .map(|liquidity| {
+                Pool::new(
+                    token0.clone(),
+                    token1.clone(),
+                    FeeAmount::LOW,
+                    sqrt_price_x96,
+                    liquidity,
+                )
+                .unwrap()
+            })
+            .collect();
+        assert_eq!(
+            pools.into_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "spawns a local anvil node forking mainnet, run with `--ignored` where Foundry is installed"]
+    async fn test_watch_pool() {
+        let provider = ProviderBuilder::new().on_anvil_with_config(|anvil| {
+            anvil
+                .fork(RPC_URL.to_string())
+                .fork_block_number(17000000)
+                .block_time(1)
+        });
+        let expected = Pool::from_pool_key(
+            1,
+            FACTORY_ADDRESS,
+            address!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599"),
+            address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+            FeeAmount::LOW,
+            PROVIDER.clone(),
+            *BLOCK_ID,
+        )
+        .await
+        .unwrap();
+        let pool_address = expected.address(None, None);
+
+        let mut pools = pin!(watch_pool(provider, pool_address).await.unwrap().take(2));
+        let mut count = 0;
+        while let Some(pool) = pools.next().await {
+            // no transaction is sent to the fork, so every new block has the forked state
+            assert_eq!(pool.unwrap(), expected);
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+}
@@ -26,6 +26,7 @@
 extern crate alloc;
 
 pub mod abi;
+pub mod addresses;
 pub mod constants;
 pub mod entities;
 pub mod error;
@@ -36,6 +37,8 @@ pub mod quoter;
 pub mod self_permit;
 pub mod staker;
 pub mod swap_router;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 pub mod utils;
 
 #[cfg(feature = "extensions")]
@@ -46,8 +49,9 @@ mod tests;
 
 pub mod prelude {
     pub use crate::{
-        abi::*, constants::*, entities::*, error::*, multicall::*, nonfungible_position_manager::*,
-        payments::*, quoter::*, self_permit::*, staker::*, swap_router::*, utils::*,
+        abi::*, addresses::*, constants::*, entities::*, error::*, multicall::*,
+        nonfungible_position_manager::*, payments::*, quoter::*, self_permit::*, staker::*,
+        swap_router::*, utils::*,
     };
 
     pub use uniswap_sdk_core as sdk_core;
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use alloc::vec::Vec;
-use alloy_primitives::Bytes;
+use alloy_primitives::{Address, Bytes};
 use alloy_sol_types::{Error, SolCall};
 
 #[inline]
@@ -17,6 +17,24 @@ pub fn encode_multicall<B: Into<Bytes>>(data: Vec<B>) -> Bytes {
     }
 }
 
+/// Same as [`encode_multicall`], but also returns the 4-byte selectors of the inner calls, in
+/// order.
+#[inline]
+#[must_use]
+pub fn encode_multicall_with_meta<B: Into<Bytes>>(data: Vec<B>) -> (Bytes, Vec<[u8; 4]>) {
+    let data: Vec<Bytes> = data.into_iter().map(Into::into).collect();
+    let selectors = data
+        .iter()
+        .map(|calldata| {
+            let mut selector = [0_u8; 4];
+            let len = calldata.len().min(4);
+            selector[..len].copy_from_slice(&calldata[..len]);
+            selector
+        })
+        .collect();
+    (encode_multicall(data), selectors)
+}
+
 #[inline]
 pub fn decode_multicall<B, E>(encoded: E) -> Result<Vec<B>, Error>
 where
@@ -27,6 +45,46 @@ where
         .map(|decoded| decoded.data.into_iter().map(Into::into).collect())
 }
 
+/// Encodes a Multicall3 `tryAggregate` call, which executes every call and, unless
+/// `require_success` is set, tolerates individual failures.
+///
+/// ## Arguments
+///
+/// * `require_success`: Whether the whole call should revert if any sub-call fails
+/// * `calls`: The target addresses and calldatas of the sub-calls
+#[inline]
+#[must_use]
+pub fn encode_try_aggregate<B: Into<Bytes>>(
+    require_success: bool,
+    calls: Vec<(Address, B)>,
+) -> Bytes {
+    IMulticall3::tryAggregateCall {
+        requireSuccess: require_success,
+        calls: calls
+            .into_iter()
+            .map(|(target, call_data)| IMulticall3::Call {
+                target,
+                callData: call_data.into(),
+            })
+            .collect(),
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Decodes the return data of a Multicall3 `tryAggregate` call into the success flag and return
+/// data of each sub-call.
+#[inline]
+pub fn decode_try_aggregate<E: AsRef<[u8]>>(encoded: E) -> Result<Vec<(bool, Bytes)>, Error> {
+    IMulticall3::tryAggregateCall::abi_decode_returns(encoded.as_ref(), true).map(|decoded| {
+        decoded
+            .returnData
+            .into_iter()
+            .map(|result| (result.success, result.returnData))
+            .collect()
+    })
+}
+
 pub trait Multicall: Sized {
     fn encode_multicall(self) -> Bytes;
 
@@ -81,6 +139,58 @@ mod tests {
         }
     }
 
+    mod try_aggregate {
+        use super::*;
+        use alloy_primitives::address;
+        use alloy_sol_types::SolValue;
+
+        #[test]
+        fn test_encode_round_trip() {
+            let calls = vec![
+                (
+                    address!("0000000000000000000000000000000000000001"),
+                    hex!("aaaaaaaa").to_vec(),
+                ),
+                (
+                    address!("0000000000000000000000000000000000000002"),
+                    hex!("bbbbbbbb").to_vec(),
+                ),
+            ];
+            let encoded = encode_try_aggregate(false, calls.clone());
+            assert_eq!(encoded[..4], IMulticall3::tryAggregateCall::SELECTOR);
+
+            let decoded = IMulticall3::tryAggregateCall::abi_decode(&encoded, true).unwrap();
+            assert!(!decoded.requireSuccess);
+            assert_eq!(
+                decoded
+                    .calls
+                    .into_iter()
+                    .map(|call| (call.target, call.callData.to_vec()))
+                    .collect::<Vec<_>>(),
+                calls
+            );
+        }
+
+        #[test]
+        fn test_decode_results() {
+            let results = vec![
+                IMulticall3::Result {
+                    success: true,
+                    returnData: hex!("01").into(),
+                },
+                IMulticall3::Result {
+                    success: false,
+                    returnData: Bytes::new(),
+                },
+            ];
+            let encoded = (results,).abi_encode_params();
+            assert_eq!(
+                decode_try_aggregate(encoded).unwrap(),
+                vec![(true, hex!("01").into()), (false, Bytes::new())]
+            );
+        }
+    }
+
     mod decode {
         use super::*;
 
@@ -699,6 +699,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_call_parameters_increase_use_native() {
+        let mut position = Position::new(
+            POOL_1_WETH.clone(),
+            1,
+            -FeeAmount::MEDIUM.tick_spacing().as_i32(),
+            FeeAmount::MEDIUM.tick_spacing().as_i32(),
+        );
+        let MethodParameters { calldata, value } = add_call_parameters(
+            &mut position,
+            AddLiquidityOptions {
+                slippage_tolerance: SLIPPAGE_TOLERANCE.clone(),
+                deadline: DEADLINE,
+                use_native: Some(ETHER.clone()),
+                token0_permit: None,
+                token1_permit: None,
+                specific_opts: AddLiquiditySpecificOptions::Increase(IncreaseSpecificOptions {
+                    token_id: TOKEN_ID,
+                }),
+            },
+        )
+        .unwrap();
+        assert_eq!(value, uint!(1_U256));
+        let calldatas = Vec::<Bytes>::decode_multicall(calldata).unwrap();
+        assert_eq!(calldatas.len(), 2);
+        assert!(
+            calldatas[0].starts_with(&INonfungiblePositionManager::increaseLiquidityCall::SELECTOR)
+        );
+        assert_eq!(calldatas[1], encode_refund_eth());
+    }
+
     #[test]
     fn test_collect_call_parameters() {
         let MethodParameters { calldata, value } = collect_call_parameters(&COLLECT_OPTIONS);
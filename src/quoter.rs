@@ -1,4 +1,5 @@
-use crate::prelude::*;
+use crate::prelude::{Error, *};
+use alloc::vec::Vec;
 use alloy_primitives::{U160, U256};
 use alloy_sol_types::SolCall;
 use uniswap_sdk_core::prelude::*;
@@ -12,6 +13,58 @@ pub struct QuoteOptions {
     pub use_quoter_v2: bool,
 }
 
+/// The decoded result of a QuoterV2 `quoteExactInputSingle` call.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuoteExactInputSingleResultV2 {
+    /// The amount of the output token that would be received
+    pub amount_out: U256,
+    /// The sqrt price of the pool after the swap
+    pub sqrt_price_x96_after: U160,
+    /// The number of initialized ticks that the swap crosses
+    pub initialized_ticks_crossed: u32,
+    /// The estimated gas used by the swap
+    pub gas_estimate: U256,
+}
+
+/// The decoded result of a QuoterV2 `quoteExactOutputSingle` call.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuoteExactOutputSingleResultV2 {
+    /// The amount of the input token that would be required
+    pub amount_in: U256,
+    /// The sqrt price of the pool after the swap
+    pub sqrt_price_x96_after: U160,
+    /// The number of initialized ticks that the swap crosses
+    pub initialized_ticks_crossed: u32,
+    /// The estimated gas used by the swap
+    pub gas_estimate: U256,
+}
+
+/// The decoded result of a QuoterV2 `quoteExactInput` call.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuoteExactInputResultV2 {
+    /// The amount of the output token that would be received
+    pub amount_out: U256,
+    /// The sqrt price of each pool in the path after the swap
+    pub sqrt_price_x96_after_list: Vec<U160>,
+    /// The number of initialized ticks that the swap crosses in each pool in the path
+    pub initialized_ticks_crossed_list: Vec<u32>,
+    /// The estimated gas used by the swap
+    pub gas_estimate: U256,
+}
+
+/// The decoded result of a QuoterV2 `quoteExactOutput` call.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuoteExactOutputResultV2 {
+    /// The amount of the input token that would be required
+    pub amount_in: U256,
+    /// The sqrt price of each pool in the path after the swap
+    pub sqrt_price_x96_after_list: Vec<U160>,
+    /// The number of initialized ticks that the swap crosses in each pool in the path
+    pub initialized_ticks_crossed_list: Vec<u32>,
+    /// The estimated gas used by the swap
+    pub gas_estimate: U256,
+}
+
 /// Produces the on-chain method name of the appropriate function within QuoterV2,
 /// and the relevant hex encoded parameters.
 ///
@@ -21,13 +74,18 @@ pub struct QuoteOptions {
 /// * `amount`: The amount of the quote, either an amount in, or an amount out
 /// * `trade_type`: The trade type, either exact input or exact output
 /// * `options`: The optional params including price limit and Quoter contract switch
+///
+/// ## Errors
+///
+/// Returns the error of [`encode_route_to_path`] if the pools of a multihop route do not
+/// connect its input to its output.
 #[inline]
 pub fn quote_call_parameters<TInput, TOutput, TP>(
     route: &Route<TInput, TOutput, TP>,
     amount: &CurrencyAmount<impl BaseCurrency>,
     trade_type: TradeType,
     options: Option<QuoteOptions>,
-) -> MethodParameters
+) -> Result<MethodParameters, Error>
 where
     TInput: BaseCurrency,
     TOutput: BaseCurrency,
@@ -90,7 +148,7 @@ where
             options.sqrt_price_limit_x96.is_zero(),
             "MULTIHOP_PRICE_LIMIT"
         );
-        let path = encode_route_to_path(route, trade_type == TradeType::ExactOutput);
+        let path = encode_route_to_path(route, trade_type == TradeType::ExactOutput)?;
         match trade_type {
             TradeType::ExactInput => IQuoter::quoteExactInputCall {
                 path,
@@ -104,16 +162,93 @@ where
             .abi_encode(),
         }
     };
-    MethodParameters {
+    Ok(MethodParameters {
         calldata: calldata.into(),
         value: U256::ZERO,
-    }
+    })
+}
+
+/// Decodes the return data of a QuoterV2 `quoteExactInputSingle` call.
+///
+/// ## Arguments
+///
+/// * `encoded`: The ABI encoded return data of the call
+#[inline]
+pub fn decode_quote_exact_input_single_v2<E: AsRef<[u8]>>(
+    encoded: E,
+) -> Result<QuoteExactInputSingleResultV2, alloy_sol_types::Error> {
+    IQuoterV2::quoteExactInputSingleCall::abi_decode_returns(encoded.as_ref(), true).map(
+        |decoded| QuoteExactInputSingleResultV2 {
+            amount_out: decoded.amountOut,
+            sqrt_price_x96_after: decoded.sqrtPriceX96After,
+            initialized_ticks_crossed: decoded.initializedTicksCrossed,
+            gas_estimate: decoded.gasEstimate,
+        },
+    )
+}
+
+/// Decodes the return data of a QuoterV2 `quoteExactOutputSingle` call.
+///
+/// ## Arguments
+///
+/// * `encoded`: The ABI encoded return data of the call
+#[inline]
+pub fn decode_quote_exact_output_single_v2<E: AsRef<[u8]>>(
+    encoded: E,
+) -> Result<QuoteExactOutputSingleResultV2, alloy_sol_types::Error> {
+    IQuoterV2::quoteExactOutputSingleCall::abi_decode_returns(encoded.as_ref(), true).map(
+        |decoded| QuoteExactOutputSingleResultV2 {
+            amount_in: decoded.amountIn,
+            sqrt_price_x96_after: decoded.sqrtPriceX96After,
+            initialized_ticks_crossed: decoded.initializedTicksCrossed,
+            gas_estimate: decoded.gasEstimate,
+        },
+    )
+}
+
+/// Decodes the return data of a QuoterV2 `quoteExactInput` call.
+///
+/// ## Arguments
+///
+/// * `encoded`: The ABI encoded return data of the call
+#[inline]
+pub fn decode_quote_exact_input_v2<E: AsRef<[u8]>>(
+    encoded: E,
+) -> Result<QuoteExactInputResultV2, alloy_sol_types::Error> {
+    IQuoterV2::quoteExactInputCall::abi_decode_returns(encoded.as_ref(), true).map(|decoded| {
+        QuoteExactInputResultV2 {
+            amount_out: decoded.amountOut,
+            sqrt_price_x96_after_list: decoded.sqrtPriceX96AfterList,
+            initialized_ticks_crossed_list: decoded.initializedTicksCrossedList,
+            gas_estimate: decoded.gasEstimate,
+        }
+    })
+}
+
+/// Decodes the return data of a QuoterV2 `quoteExactOutput` call.
+///
+/// ## Arguments
+///
+/// * `encoded`: The ABI encoded return data of the call
+#[inline]
+pub fn decode_quote_exact_output_v2<E: AsRef<[u8]>>(
+    encoded: E,
+) -> Result<QuoteExactOutputResultV2, alloy_sol_types::Error> {
+    IQuoterV2::quoteExactOutputCall::abi_decode_returns(encoded.as_ref(), true).map(|decoded| {
+        QuoteExactOutputResultV2 {
+            amount_in: decoded.amountIn,
+            sqrt_price_x96_after_list: decoded.sqrtPriceX96AfterList,
+            initialized_ticks_crossed_list: decoded.initializedTicksCrossedList,
+            gas_estimate: decoded.gasEstimate,
+        }
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tests::*;
+    use alloc::vec;
     use once_cell::sync::Lazy;
 
     static POOL_0_1: Lazy<Pool<TickListDataProvider>> =
@@ -121,6 +256,90 @@ mod tests {
     static POOL_1_WETH: Lazy<Pool<TickListDataProvider>> =
         Lazy::new(|| make_pool(TOKEN1.clone(), WETH.clone()));
 
+    #[test]
+    fn decodes_quote_exact_input_single_v2_result() {
+        let encoded = IQuoterV2::quoteExactInputSingleCall::abi_encode_returns(&(
+            U256::from(98),
+            SQRT_RATIO_X96,
+            2_u32,
+            U256::from(80000),
+        ));
+        assert_eq!(
+            decode_quote_exact_input_single_v2(encoded).unwrap(),
+            QuoteExactInputSingleResultV2 {
+                amount_out: U256::from(98),
+                sqrt_price_x96_after: SQRT_RATIO_X96,
+                initialized_ticks_crossed: 2,
+                gas_estimate: U256::from(80000),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_quote_exact_output_single_v2_result() {
+        let encoded = IQuoterV2::quoteExactOutputSingleCall::abi_encode_returns(&(
+            U256::from(102),
+            SQRT_RATIO_X96,
+            1_u32,
+            U256::from(80000),
+        ));
+        assert_eq!(
+            decode_quote_exact_output_single_v2(encoded).unwrap(),
+            QuoteExactOutputSingleResultV2 {
+                amount_in: U256::from(102),
+                sqrt_price_x96_after: SQRT_RATIO_X96,
+                initialized_ticks_crossed: 1,
+                gas_estimate: U256::from(80000),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_quote_exact_input_v2_result() {
+        let encoded = IQuoterV2::quoteExactInputCall::abi_encode_returns(&(
+            U256::from(96),
+            vec![SQRT_RATIO_X96, SQRT_RATIO_X96 + U160::from(1)],
+            vec![2_u32, 0],
+            U256::from(160000),
+        ));
+        assert_eq!(
+            decode_quote_exact_input_v2(encoded).unwrap(),
+            QuoteExactInputResultV2 {
+                amount_out: U256::from(96),
+                sqrt_price_x96_after_list: vec![SQRT_RATIO_X96, SQRT_RATIO_X96 + U160::from(1)],
+                initialized_ticks_crossed_list: vec![2, 0],
+                gas_estimate: U256::from(160000),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_quote_exact_output_v2_result() {
+        let encoded = IQuoterV2::quoteExactOutputCall::abi_encode_returns(&(
+            U256::from(104),
+            vec![SQRT_RATIO_X96],
+            vec![3_u32],
+            U256::from(90000),
+        ));
+        assert_eq!(
+            decode_quote_exact_output_v2(encoded).unwrap(),
+            QuoteExactOutputResultV2 {
+                amount_in: U256::from(104),
+                sqrt_price_x96_after_list: vec![SQRT_RATIO_X96],
+                initialized_ticks_crossed_list: vec![3],
+                gas_estimate: U256::from(90000),
+            }
+        );
+        // the return data of a single-pool quote is not that of a path quote
+        let encoded = IQuoterV2::quoteExactOutputSingleCall::abi_encode_returns(&(
+            U256::from(104),
+            SQRT_RATIO_X96,
+            3_u32,
+            U256::from(90000),
+        ));
+        assert!(decode_quote_exact_output_v2(encoded).is_err());
+    }
+
     mod single_trade_input {
         use super::*;
         use alloy_primitives::hex;
@@ -135,7 +354,8 @@ mod tests {
             .unwrap();
             let input_amount = trade.input_amount().unwrap();
             let params =
-                quote_call_parameters(&trade.swaps[0].route, &input_amount, trade.trade_type, None);
+                quote_call_parameters(&trade.swaps[0].route, &input_amount, trade.trade_type, None)
+                    .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("f7729d43000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000bb800000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000000")
@@ -157,7 +377,8 @@ mod tests {
                 &output_amount,
                 trade.trade_type,
                 None,
-            );
+            )
+            .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("30d07f21000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000bb800000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000000")
@@ -182,7 +403,8 @@ mod tests {
                 &trade.input_amount().unwrap(),
                 trade.trade_type,
                 None,
-            );
+            )
+            .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("cdca17530000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006400000000000000000000000000000000000000000000000000000000000000420000000000000000000000000000000000000001000bb80000000000000000000000000000000000000002000bb8c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000000000000000000000000000000000000000000000000000000000000")
@@ -207,7 +429,8 @@ mod tests {
                 &trade.output_amount().unwrap(),
                 trade.trade_type,
                 None,
-            );
+            )
+            .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("2f80bb1d000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000042c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000bb80000000000000000000000000000000000000002000bb80000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000")
@@ -231,7 +454,8 @@ mod tests {
                     sqrt_price_limit_x96: U160::from_limbs([0, 0, 1]),
                     use_quoter_v2: false,
                 }),
-            );
+            )
+            .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("f7729d43000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000bb800000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100000000000000000000000000000000")
@@ -261,7 +485,8 @@ mod tests {
                     sqrt_price_limit_x96: U160::ZERO,
                     use_quoter_v2: true,
                 }),
-            );
+            )
+            .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("c6a5026a0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000bb80000000000000000000000000000000000000000000000000000000000000000"),
@@ -285,7 +510,8 @@ mod tests {
                     sqrt_price_limit_x96: U160::ZERO,
                     use_quoter_v2: true,
                 }),
-            );
+            )
+            .unwrap();
             assert_eq!(
                 params.calldata.to_vec(),
                 hex!("bd21704a0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000bb80000000000000000000000000000000000000000000000000000000000000000"),
@@ -1,4 +1,4 @@
-use super::abi::ISelfPermit;
+use super::abi::{IAllowanceTransfer, ISelfPermit};
 use alloy_primitives::{Bytes, PrimitiveSignature, B256, U256};
 use alloy_sol_types::{eip712_domain, Eip712Domain, SolCall, SolStruct};
 use uniswap_sdk_core::prelude::*;
@@ -171,10 +171,67 @@ pub fn encode_permit(token: &impl BaseCurrency, options: PermitOptions) -> Bytes
     .into()
 }
 
+/// Encodes a Permit2 `permit(owner, PermitSingle, signature)` call granting `spender` an allowance
+/// of a single token.
+///
+/// The calldata is meant for the Permit2 contract at [`PERMIT2_ADDRESS`], not for the router.
+///
+/// ## Arguments
+///
+/// * `owner`: The owner of the token, who signed the permit
+/// * `permit_single`: The token, amount, expiration, nonce, spender and signature deadline
+/// * `signature`: The owner's signature over the EIP-712 hash of `permit_single`
+#[inline]
+#[must_use]
+pub fn encode_permit2(
+    owner: Address,
+    permit_single: IAllowanceTransfer::PermitSingle,
+    signature: PrimitiveSignature,
+) -> Bytes {
+    IAllowanceTransfer::permit_0Call {
+        owner,
+        permitSingle: permit_single,
+        signature: signature.as_bytes().into(),
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Encodes a Permit2 `permit(owner, PermitBatch, signature)` call granting `spender` allowances of
+/// several tokens at once.
+///
+/// The calldata is meant for the Permit2 contract at [`PERMIT2_ADDRESS`], not for the router.
+///
+/// ## Arguments
+///
+/// * `owner`: The owner of the tokens, who signed the permit
+/// * `permit_batch`: The per-token details along with the spender and signature deadline
+/// * `signature`: The owner's signature over the EIP-712 hash of `permit_batch`
+#[inline]
+#[must_use]
+pub fn encode_permit2_batch(
+    owner: Address,
+    permit_batch: IAllowanceTransfer::PermitBatch,
+    signature: PrimitiveSignature,
+) -> Bytes {
+    IAllowanceTransfer::permit_1Call {
+        owner,
+        permitBatch: permit_batch,
+        signature: signature.as_bytes().into(),
+    }
+    .abi_encode()
+    .into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex, uint};
+    use alloc::vec;
+    use alloy_primitives::{
+        address,
+        aliases::{U160, U48},
+        hex, uint,
+    };
     use once_cell::sync::Lazy;
     use uniswap_sdk_core::token;
 
@@ -212,4 +269,54 @@ mod tests {
         );
         assert_eq!(calldata, hex!("4659a4940000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000007b000000000000000000000000000000000000000000000000000000000000007b000000000000000000000000000000000000000000000000000000000000001b00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002").to_vec());
     }
+
+    fn permit_details(
+        token: Address,
+        amount: u64,
+        nonce: u64,
+    ) -> IAllowanceTransfer::PermitDetails {
+        IAllowanceTransfer::PermitDetails {
+            token,
+            amount: U160::from(amount),
+            expiration: U48::from(456_u64),
+            nonce: U48::from(nonce),
+        }
+    }
+
+    #[test]
+    fn test_encode_permit2() {
+        let calldata = encode_permit2(
+            address!("0000000000000000000000000000000000000003"),
+            IAllowanceTransfer::PermitSingle {
+                details: permit_details(TOKEN.address(), 123, 1),
+                spender: address!("0000000000000000000000000000000000000002"),
+                sigDeadline: uint!(789_U256),
+            },
+            PrimitiveSignature::new(uint!(1_U256), uint!(2_U256), false),
+        );
+        assert_eq!(calldata[..4], IAllowanceTransfer::permit_0Call::SELECTOR);
+        assert_eq!(calldata, hex!("2b67b57000000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000007b00000000000000000000000000000000000000000000000000000000000001c800000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000031500000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000041000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000021b00000000000000000000000000000000000000000000000000000000000000").to_vec());
+    }
+
+    #[test]
+    fn test_encode_permit2_batch() {
+        let calldata = encode_permit2_batch(
+            address!("0000000000000000000000000000000000000003"),
+            IAllowanceTransfer::PermitBatch {
+                details: vec![
+                    permit_details(TOKEN.address(), 123, 1),
+                    permit_details(
+                        address!("0000000000000000000000000000000000000004"),
+                        1000,
+                        2,
+                    ),
+                ],
+                spender: address!("0000000000000000000000000000000000000002"),
+                sigDeadline: uint!(789_U256),
+            },
+            PrimitiveSignature::new(uint!(1_U256), uint!(2_U256), false),
+        );
+        assert_eq!(calldata[..4], IAllowanceTransfer::permit_1Call::SELECTOR);
+        assert_eq!(calldata, hex!("2a2d80d10000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000001e000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000031500000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000007b00000000000000000000000000000000000000000000000000000000000001c80000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000003e800000000000000000000000000000000000000000000000000000000000001c800000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000041000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000021b00000000000000000000000000000000000000000000000000000000000000").to_vec());
+    }
 }
@@ -1,16 +1,46 @@
 use crate::prelude::*;
-use alloc::vec::Vec;
-use alloy_primitives::{Address, Bytes, U256};
+use alloc::{vec, vec::Vec};
+use alloy_primitives::{Address, Bytes, ChainId, U256, U512};
 use alloy_sol_types::{SolCall, SolValue};
+use core::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FullWithdrawOptions {
     pub claim_options: ClaimOptions,
     pub withdraw_options: WithdrawOptions,
 }
 
+/// Method parameters together with the staker contract they must be sent to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StakerMethodParameters {
+    /// The `UniswapV3Staker` contract to call
+    pub staker_address: Address,
+    /// The calldata and value of the call
+    pub parameters: MethodParameters,
+}
+
+impl StakerMethodParameters {
+    /// Targets the given parameters at the known staker deployment of a chain, see
+    /// [`resolve_staker_address`]. Construct the struct directly for non-canonical deployments.
+    ///
+    /// ## Arguments
+    ///
+    /// * `chain_id`: The chain id
+    /// * `parameters`: The parameters produced by one of the staker functions
+    #[inline]
+    #[must_use]
+    pub fn for_chain(chain_id: ChainId, parameters: MethodParameters) -> Option<Self> {
+        resolve_staker_address(chain_id).map(|staker_address| Self {
+            staker_address,
+            parameters,
+        })
+    }
+}
+
 /// Options to specify when claiming rewards.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClaimOptions {
     /// The id of the NFT
     pub token_id: U256,
@@ -21,7 +51,8 @@ pub struct ClaimOptions {
 }
 
 /// Options to specify when withdrawing a position.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawOptions {
     /// Set when withdrawing. The position will be sent to `owner` on withdraw.
     pub owner: Address,
@@ -31,7 +62,15 @@ pub struct WithdrawOptions {
 }
 
 /// Represents a unique staking program.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "TP: serde::Serialize",
+        deserialize = "TP: serde::Deserialize<'de>"
+    ))
+)]
 pub struct IncentiveKey<TP: TickDataProvider> {
     /// The token rewarded for participating in the staking program.
     pub reward_token: Address,
@@ -45,8 +84,83 @@ pub struct IncentiveKey<TP: TickDataProvider> {
     pub refundee: Address,
 }
 
+impl<TP> PartialEq for IncentiveKey<TP>
+where
+    TP: TickDataProvider<Index: PartialEq>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.reward_token == other.reward_token
+            && self.pool == other.pool
+            && self.start_time == other.start_time
+            && self.end_time == other.end_time
+            && self.refundee == other.refundee
+    }
+}
+
+impl<TP> Eq for IncentiveKey<TP> where TP: TickDataProvider<Index: Eq> {}
+
+impl<TP: TickDataProvider> Hash for IncentiveKey<TP> {
+    /// Hashes the fields identifying the program on-chain, which are a subset of those compared
+    /// for equality.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.reward_token.hash(state);
+        self.pool.token0.address().hash(state);
+        self.pool.token1.address().hash(state);
+        self.pool.fee.hash(state);
+        self.start_time.hash(state);
+        self.end_time.hash(state);
+        self.refundee.hash(state);
+    }
+}
+
+impl<TP: TickDataProvider> IncentiveKey<TP> {
+    /// Creates a validated incentive key.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reward_token`: The token rewarded for participating in the staking program.
+    /// * `pool`: The pool that the staked positions must provide in.
+    /// * `start_time`: The time when the incentive program begins.
+    /// * `end_time`: The time that the incentive program ends.
+    /// * `refundee`: The address which receives any remaining reward tokens at `end_time`.
+    #[inline]
+    pub fn new(
+        reward_token: Address,
+        pool: Pool<TP>,
+        start_time: U256,
+        end_time: U256,
+        refundee: Address,
+    ) -> Result<Self, Error> {
+        let incentive_key = Self {
+            reward_token,
+            pool,
+            start_time,
+            end_time,
+            refundee,
+        };
+        incentive_key.validate()?;
+        Ok(incentive_key)
+    }
+
+    /// Checks that the reward token and refundee are non-zero and that the program ends after it
+    /// starts, as an incentive key violating these can never be matched on-chain.
+    #[inline]
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.reward_token.is_zero()
+            || self.refundee.is_zero()
+            || self.start_time >= self.end_time
+        {
+            return Err(Error::InvalidIncentiveKey);
+        }
+        Ok(())
+    }
+}
+
+/// Converts an [`IncentiveKey`] into the `IncentiveKey` struct expected by the staker contract.
 #[inline]
-fn encode_incentive_key<TP: TickDataProvider>(
+pub fn encode_incentive_key<TP: TickDataProvider>(
     incentive_key: &IncentiveKey<TP>,
 ) -> IUniswapV3Staker::IncentiveKey {
     IUniswapV3Staker::IncentiveKey {
@@ -58,8 +172,85 @@ fn encode_incentive_key<TP: TickDataProvider>(
     }
 }
 
+/// Produces the calldata for 'stakeToken'.
+///
+/// ## Arguments
+///
+/// * `incentive_key`: The unique identifier of a staking program.
+/// * `token_id`: The id of the NFT to stake.
+#[inline]
+pub fn encode_stake<TP: TickDataProvider>(
+    incentive_key: &IncentiveKey<TP>,
+    token_id: U256,
+) -> Bytes {
+    IUniswapV3Staker::stakeTokenCall {
+        key: encode_incentive_key(incentive_key),
+        tokenId: token_id,
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Produces the calldata for 'unstakeToken'.
+///
+/// ## Arguments
+///
+/// * `incentive_key`: The unique identifier of a staking program.
+/// * `token_id`: The id of the NFT to unstake.
+#[inline]
+pub fn encode_unstake<TP: TickDataProvider>(
+    incentive_key: &IncentiveKey<TP>,
+    token_id: U256,
+) -> Bytes {
+    IUniswapV3Staker::unstakeTokenCall {
+        key: encode_incentive_key(incentive_key),
+        tokenId: token_id,
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Produces the on-chain method name and parameters to stake a deposited position in a single
+/// staking program.
+///
+/// ## Arguments
+///
+/// * `incentive_key`: The unique identifier of a staking program.
+/// * `token_id`: The id of the NFT to stake.
+#[inline]
+pub fn stake_token<TP: TickDataProvider>(
+    incentive_key: &IncentiveKey<TP>,
+    token_id: U256,
+) -> MethodParameters {
+    MethodParameters {
+        calldata: encode_stake(incentive_key, token_id),
+        value: U256::ZERO,
+    }
+}
+
+/// Produces the on-chain method name and parameters to unstake a position from a single staking
+/// program.
+///
+/// ## Arguments
+///
+/// * `incentive_key`: The unique identifier of a staking program.
+/// * `token_id`: The id of the NFT to unstake.
+#[inline]
+pub fn unstake_token<TP: TickDataProvider>(
+    incentive_key: &IncentiveKey<TP>,
+    token_id: U256,
+) -> MethodParameters {
+    MethodParameters {
+        calldata: encode_unstake(incentive_key, token_id),
+        value: U256::ZERO,
+    }
+}
+
 /// To claim rewards, must unstake and then claim.
 ///
+/// The returned calldatas can be combined with the other staker primitives into a custom
+/// multicall. All of them must be sent to the staker contract.
+///
 /// ## Arguments
 ///
 /// * `incentive_key`: The unique identifier of a staking program.
@@ -68,18 +259,60 @@ fn encode_incentive_key<TP: TickDataProvider>(
 /// ## Returns
 ///
 /// The calldatas for 'unstakeToken' and 'claimReward'.
+///
+/// ## Examples
+///
+/// Claim from one program and move the position into another one without re-staking in the
+/// first:
+///
+/// ```
+/// use alloy_primitives::{address, Bytes, U256};
+/// use uniswap_sdk_core::{prelude::*, token};
+/// use uniswap_v3_sdk::prelude::*;
+///
+/// let pool = Pool::new(
+///     token!(1, "2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599", 8, "WBTC"),
+///     token!(1, "C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", 18, "WETH"),
+///     FeeAmount::MEDIUM,
+///     encode_sqrt_ratio_x96(1, 1),
+///     0,
+/// )
+/// .unwrap();
+/// let ended = IncentiveKey::new(
+///     address!("1f9840a85d5aF5bf1D1762F925BDADdC4201F984"),
+///     pool,
+///     U256::from(100),
+///     U256::from(200),
+///     address!("0000000000000000000000000000000000000004"),
+/// )
+/// .unwrap();
+/// let next = IncentiveKey {
+///     start_time: U256::from(200),
+///     end_time: U256::from(300),
+///     ..ended.clone()
+/// };
+/// let token_id = U256::from(1);
+///
+/// let mut calldatas = Vec::new();
+/// calldatas.extend(encode_claim(
+///     &ended,
+///     ClaimOptions {
+///         token_id,
+///         recipient: address!("0000000000000000000000000000000000000003"),
+///         amount: None,
+///     },
+/// ));
+/// calldatas.push(encode_stake(&next, token_id));
+/// let calldata = encode_multicall(calldatas);
+/// assert_eq!(Vec::<Bytes>::decode_multicall(&calldata).unwrap().len(), 3);
+/// ```
 #[inline]
-fn encode_claim<TP: TickDataProvider>(
+pub fn encode_claim<TP: TickDataProvider>(
     incentive_key: &IncentiveKey<TP>,
     options: ClaimOptions,
 ) -> [Bytes; 2] {
     [
-        IUniswapV3Staker::unstakeTokenCall {
-            key: encode_incentive_key(incentive_key),
-            tokenId: options.token_id,
-        }
-        .abi_encode()
-        .into(),
+        encode_unstake(incentive_key, options.token_id),
         IUniswapV3Staker::claimRewardCall {
             rewardToken: incentive_key.reward_token,
             to: options.recipient,
@@ -111,15 +344,56 @@ pub fn collect_rewards<TP: TickDataProvider>(
         // unstakes and claims for the unique program
         calldatas.extend(encode_claim(incentive_key, options));
         // re-stakes the position for the unique program
-        calldatas.push(
-            IUniswapV3Staker::stakeTokenCall {
-                key: encode_incentive_key(incentive_key),
-                tokenId: options.token_id,
-            }
-            .abi_encode()
-            .into(),
-        );
+        calldatas.push(encode_stake(incentive_key, options.token_id));
+    }
+    MethodParameters {
+        calldata: encode_multicall(calldatas),
+        value: U256::ZERO,
     }
+}
+
+/// Like [`collect_rewards`], but first checks every incentive key with [`IncentiveKey::validate`].
+///
+/// ## Arguments
+///
+/// * `incentive_keys`: An array of IncentiveKeys that `tokenId` is staked in.
+/// * `options`: ClaimOptions to specify tokenId, recipient, and amount wanting to collect.
+///
+/// ## Errors
+///
+/// Returns [`Error::InvalidIncentiveKey`] if any of the incentive keys is invalid.
+#[inline]
+pub fn try_collect_rewards<TP: TickDataProvider>(
+    incentive_keys: &[IncentiveKey<TP>],
+    options: ClaimOptions,
+) -> Result<MethodParameters, Error> {
+    incentive_keys.iter().try_for_each(IncentiveKey::validate)?;
+    Ok(collect_rewards(incentive_keys, options))
+}
+
+/// Claim rewards from a single program without re-staking or withdrawing the position.
+///
+/// Unlike [`collect_rewards`], the position is not re-staked. If `unstake` is false, the position
+/// is assumed to be unstaked already so its rewards have accrued, and only the `claimReward` call
+/// is produced. It is then returned as a plain call instead of being wrapped in a multicall.
+///
+/// ## Arguments
+///
+/// * `incentive_key`: The unique identifier of the staking program.
+/// * `options`: ClaimOptions to specify tokenId, recipient, and amount wanting to collect.
+/// * `unstake`: Whether to unstake the position from the program before claiming.
+#[inline]
+pub fn collect_single<TP: TickDataProvider>(
+    incentive_key: &IncentiveKey<TP>,
+    options: ClaimOptions,
+    unstake: bool,
+) -> MethodParameters {
+    let [unstake_calldata, claim_calldata] = encode_claim(incentive_key, options);
+    let calldatas = if unstake {
+        vec![unstake_calldata, claim_calldata]
+    } else {
+        vec![claim_calldata]
+    };
     MethodParameters {
         calldata: encode_multicall(calldatas),
         value: U256::ZERO,
@@ -161,6 +435,78 @@ pub fn withdraw_token<TP: TickDataProvider>(
     }
 }
 
+/// Like [`withdraw_token`], but first checks every incentive key with [`IncentiveKey::validate`].
+///
+/// ## Arguments
+///
+/// * `incentive_keys`: A list of incentiveKeys to unstake from.
+/// * `withdraw_options`: Options for producing claim calldata and withdraw calldata.
+///
+/// ## Errors
+///
+/// Returns [`Error::InvalidIncentiveKey`] if any of the incentive keys is invalid.
+#[inline]
+pub fn try_withdraw_token<TP: TickDataProvider>(
+    incentive_keys: &[IncentiveKey<TP>],
+    withdraw_options: FullWithdrawOptions,
+) -> Result<MethodParameters, Error> {
+    incentive_keys.iter().try_for_each(IncentiveKey::validate)?;
+    Ok(withdraw_token(incentive_keys, withdraw_options))
+}
+
+/// Computes the amount of rewards owed for a staked position, mirroring `RewardMath.
+/// computeRewardAmount` of the staker contract.
+///
+/// Note: the rewards are spread over `max(end_time, now) - start_time`, so the amount owed to
+/// an unclaimed position keeps decreasing after the program ends.
+///
+/// ## Arguments
+///
+/// * `incentive_key`: The unique identifier of the staking program.
+/// * `liquidity`: The liquidity of the staked position.
+/// * `seconds_per_liquidity_inside_x128`: The seconds per liquidity inside the position's range
+///   accrued since it was staked, as a Q128.128.
+/// * `total_reward_unclaimed`: The total amount of unclaimed rewards left for the program.
+/// * `total_seconds_claimed_x128`: The total seconds claimed for the program, as a Q128.128.
+/// * `now`: The current block timestamp.
+///
+/// ## Errors
+///
+/// Returns [`Error::IncentiveNotStarted`] if `now` is before `start_time`,
+/// [`Error::InvalidIncentiveKey`] if the program does not end after it starts, and
+/// [`Error::MulDivOverflow`] if no seconds are left unclaimed or the reward does not fit in a
+/// `uint256`, where the contract's `mulDiv` reverts.
+#[inline]
+pub fn compute_reward_amount<TP: TickDataProvider>(
+    incentive_key: &IncentiveKey<TP>,
+    liquidity: u128,
+    seconds_per_liquidity_inside_x128: U256,
+    total_reward_unclaimed: U256,
+    total_seconds_claimed_x128: U256,
+    now: U256,
+) -> Result<U256, Error> {
+    if now < incentive_key.start_time {
+        return Err(Error::IncentiveNotStarted);
+    }
+    if incentive_key.start_time >= incentive_key.end_time {
+        return Err(Error::InvalidIncentiveKey);
+    }
+
+    // the contract multiplies unchecked in `uint256`
+    let seconds_inside_x128 = seconds_per_liquidity_inside_x128.wrapping_mul(U256::from(liquidity));
+    let total_seconds_unclaimed_x128 =
+        (U512::from(incentive_key.end_time.max(now) - incentive_key.start_time) << 128)
+            .checked_sub(U512::from(total_seconds_claimed_x128))
+            .filter(|seconds| !seconds.is_zero())
+            .ok_or(Error::MulDivOverflow)?;
+
+    U256::uint_try_from(
+        U512::from(total_reward_unclaimed) * U512::from(seconds_inside_x128)
+            / total_seconds_unclaimed_x128,
+    )
+    .map_err(|_| Error::MulDivOverflow)
+}
+
 #[inline]
 pub fn encode_deposit<TP: TickDataProvider>(incentive_keys: &[IncentiveKey<TP>]) -> Bytes {
     if incentive_keys.len() == 1 {
@@ -178,7 +524,7 @@ pub fn encode_deposit<TP: TickDataProvider>(incentive_keys: &[IncentiveKey<TP>])
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::*;
+    use crate::{prelude::Error, tests::*};
     use alloy_primitives::{address, hex, uint};
     use once_cell::sync::Lazy;
     use uniswap_sdk_core::{prelude::*, token};
@@ -227,6 +573,229 @@ mod tests {
         },
     });
 
+    #[test]
+    fn test_staker_method_parameters_for_chain() {
+        let parameters = stake_token(&INCENTIVE_KEY, TOKEN_ID);
+        let targeted = StakerMethodParameters::for_chain(1, parameters.clone()).unwrap();
+        assert_eq!(
+            targeted.staker_address,
+            address!("e34139463bA50bD61336E0c446Bd8C0867c6fE65")
+        );
+        assert_eq!(targeted.parameters, parameters);
+        assert_eq!(StakerMethodParameters::for_chain(8453, parameters), None);
+    }
+
+    #[test]
+    fn test_incentive_key_hash() {
+        extern crate std;
+        use std::collections::HashSet;
+
+        let keys: HashSet<_> = INCENTIVE_KEYS
+            .iter()
+            .chain(INCENTIVE_KEYS.iter())
+            .cloned()
+            .collect();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&*INCENTIVE_KEY));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&*INCENTIVE_KEY).unwrap();
+        let incentive_key: IncentiveKey<NoTickDataProvider> = serde_json::from_str(&json).unwrap();
+        assert_eq!(incentive_key, *INCENTIVE_KEY);
+
+        let json = serde_json::to_string(&*WITHDRAW_OPTIONS).unwrap();
+        let withdraw_options: FullWithdrawOptions = serde_json::from_str(&json).unwrap();
+        assert_eq!(withdraw_options, *WITHDRAW_OPTIONS);
+    }
+
+    #[test]
+    fn test_incentive_key_new_validates() {
+        let new = |reward_token, start_time: u64, end_time: u64, refundee| {
+            IncentiveKey::new(
+                reward_token,
+                POOL_0_1.clone(),
+                U256::from(start_time),
+                U256::from(end_time),
+                refundee,
+            )
+        };
+        assert_eq!(
+            encode_incentive_key(&new(REWARD.address(), 100, 200, INCENTIVE_KEY.refundee).unwrap()),
+            encode_incentive_key(&INCENTIVE_KEY)
+        );
+        assert!(matches!(
+            new(Address::ZERO, 100, 200, INCENTIVE_KEY.refundee),
+            Err(Error::InvalidIncentiveKey)
+        ));
+        assert!(matches!(
+            new(REWARD.address(), 100, 200, Address::ZERO),
+            Err(Error::InvalidIncentiveKey)
+        ));
+        assert!(matches!(
+            new(REWARD.address(), 200, 100, INCENTIVE_KEY.refundee),
+            Err(Error::InvalidIncentiveKey)
+        ));
+        assert!(matches!(
+            new(REWARD.address(), 100, 100, INCENTIVE_KEY.refundee),
+            Err(Error::InvalidIncentiveKey)
+        ));
+    }
+
+    #[test]
+    fn test_try_variants_validate_incentive_keys() {
+        let options = ClaimOptions {
+            token_id: TOKEN_ID,
+            recipient: RECIPIENT,
+            amount: None,
+        };
+        assert_eq!(
+            try_collect_rewards(&[INCENTIVE_KEY.clone()], options).unwrap(),
+            collect_rewards(&[INCENTIVE_KEY.clone()], options)
+        );
+        assert_eq!(
+            try_withdraw_token(&[INCENTIVE_KEY.clone()], WITHDRAW_OPTIONS.clone()).unwrap(),
+            withdraw_token(&[INCENTIVE_KEY.clone()], WITHDRAW_OPTIONS.clone())
+        );
+
+        let invalid_keys = [
+            INCENTIVE_KEY.clone(),
+            IncentiveKey {
+                start_time: uint!(300_U256),
+                ..INCENTIVE_KEY.clone()
+            },
+        ];
+        assert!(matches!(
+            try_collect_rewards(&invalid_keys, options),
+            Err(Error::InvalidIncentiveKey)
+        ));
+        assert!(matches!(
+            try_withdraw_token(&invalid_keys, WITHDRAW_OPTIONS.clone()),
+            Err(Error::InvalidIncentiveKey)
+        ));
+    }
+
+    mod compute_reward_amount {
+        use super::*;
+
+        fn reward(
+            liquidity: u128,
+            seconds_per_liquidity_inside_x128: U256,
+            total_reward_unclaimed: U256,
+            now: u64,
+        ) -> U256 {
+            // start_time 100, end_time 200
+            compute_reward_amount(
+                &INCENTIVE_KEY,
+                liquidity,
+                seconds_per_liquidity_inside_x128,
+                total_reward_unclaimed,
+                U256::ZERO,
+                U256::from(now),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn half_the_liquidity_over_20_percent_of_the_total_duration() {
+            let seconds_per_liquidity = (U256::from(20) << 128) / U256::from(10);
+            assert_eq!(
+                reward(5, seconds_per_liquidity, U256::from(1000), 120),
+                U256::from(100)
+            );
+        }
+
+        #[test]
+        fn all_the_liquidity_for_the_duration() {
+            assert_eq!(
+                reward(100, U256::from(1) << 128, U256::from(1000), 200),
+                U256::from(1000)
+            );
+        }
+
+        #[test]
+        fn all_the_liquidity_for_the_duration_claimed_after_the_end_time() {
+            assert_eq!(
+                reward(100, U256::from(1) << 128, U256::from(1000), 300),
+                U256::from(500)
+            );
+        }
+
+        #[test]
+        fn does_not_overflow_for_large_rewards() {
+            assert_eq!(
+                reward(100, U256::from(1) << 127, U256::MAX, 200),
+                U256::MAX >> 1
+            );
+        }
+
+        #[test]
+        fn errors_before_the_start_time() {
+            assert!(matches!(
+                compute_reward_amount(
+                    &INCENTIVE_KEY,
+                    100,
+                    U256::ZERO,
+                    U256::from(1000),
+                    U256::ZERO,
+                    U256::from(99),
+                ),
+                Err(Error::IncentiveNotStarted)
+            ));
+        }
+
+        #[test]
+        fn errors_for_a_program_without_duration() {
+            let incentive_key = IncentiveKey {
+                end_time: INCENTIVE_KEY.start_time,
+                ..INCENTIVE_KEY.clone()
+            };
+            assert!(matches!(
+                compute_reward_amount(
+                    &incentive_key,
+                    100,
+                    U256::from(1) << 128,
+                    U256::from(1000),
+                    U256::ZERO,
+                    incentive_key.start_time,
+                ),
+                Err(Error::InvalidIncentiveKey)
+            ));
+        }
+
+        #[test]
+        fn errors_when_the_reward_overflows() {
+            assert!(matches!(
+                compute_reward_amount(
+                    &INCENTIVE_KEY,
+                    1,
+                    U256::MAX,
+                    U256::MAX,
+                    U256::ZERO,
+                    U256::from(200),
+                ),
+                Err(Error::MulDivOverflow)
+            ));
+        }
+
+        #[test]
+        fn errors_when_all_the_seconds_are_claimed() {
+            assert!(matches!(
+                compute_reward_amount(
+                    &INCENTIVE_KEY,
+                    100,
+                    U256::from(1) << 128,
+                    U256::from(1000),
+                    U256::from(100) << 128,
+                    U256::from(200),
+                ),
+                Err(Error::MulDivOverflow)
+            ));
+        }
+    }
+
     #[test]
     fn test_collect_rewards_succeeds_with_amount() {
         let options = ClaimOptions {
@@ -243,6 +812,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_collect_rewards_selectors() {
+        let options = ClaimOptions {
+            token_id: TOKEN_ID,
+            recipient: RECIPIENT,
+            amount: None,
+        };
+        let MethodParameters { calldata, .. } = collect_rewards(&[INCENTIVE_KEY.clone()], options);
+        let calldatas = Vec::<Bytes>::decode_multicall(&calldata).unwrap();
+        let (encoded, selectors) = encode_multicall_with_meta(calldatas);
+        assert_eq!(encoded, calldata);
+        assert_eq!(
+            selectors,
+            vec![
+                IUniswapV3Staker::unstakeTokenCall::SELECTOR,
+                IUniswapV3Staker::claimRewardCall::SELECTOR,
+                IUniswapV3Staker::stakeTokenCall::SELECTOR,
+            ]
+        );
+    }
+
     #[test]
     fn test_collect_rewards_succeeds_no_amount() {
         let options = ClaimOptions {
@@ -274,6 +864,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_claim_and_stake_match_collect_rewards() {
+        let options = ClaimOptions {
+            token_id: TOKEN_ID,
+            recipient: RECIPIENT,
+            amount: None,
+        };
+        let mut calldatas = encode_claim(&INCENTIVE_KEY, options).to_vec();
+        calldatas.push(encode_stake(&INCENTIVE_KEY, TOKEN_ID));
+        assert_eq!(
+            encode_multicall(calldatas),
+            collect_rewards(&[INCENTIVE_KEY.clone()], options).calldata
+        );
+        assert_eq!(
+            encode_unstake(&INCENTIVE_KEY, TOKEN_ID),
+            encode_claim(&INCENTIVE_KEY, options)[0]
+        );
+    }
+
+    #[test]
+    fn test_collect_single() {
+        let options = ClaimOptions {
+            token_id: TOKEN_ID,
+            recipient: RECIPIENT,
+            amount: Some(uint!(1_U256)),
+        };
+        let [unstake, claim] = encode_claim(&INCENTIVE_KEY, options);
+
+        let MethodParameters { calldata, value } = collect_single(&INCENTIVE_KEY, options, false);
+        assert_eq!(value, U256::ZERO);
+        assert_eq!(calldata, claim);
+        assert_eq!(calldata[..4], IUniswapV3Staker::claimRewardCall::SELECTOR);
+
+        let MethodParameters { calldata, value } = collect_single(&INCENTIVE_KEY, options, true);
+        assert_eq!(value, U256::ZERO);
+        assert_eq!(
+            Vec::<Bytes>::decode_multicall(&calldata).unwrap(),
+            vec![unstake, claim]
+        );
+    }
+
+    #[test]
+    fn test_stake_token_and_unstake_token() {
+        let deposit = encode_deposit(&[INCENTIVE_KEY.clone()]);
+        for (MethodParameters { calldata, value }, selector) in [
+            (
+                stake_token(&INCENTIVE_KEY, TOKEN_ID),
+                IUniswapV3Staker::stakeTokenCall::SELECTOR,
+            ),
+            (
+                unstake_token(&INCENTIVE_KEY, TOKEN_ID),
+                IUniswapV3Staker::unstakeTokenCall::SELECTOR,
+            ),
+        ] {
+            assert_eq!(value, U256::ZERO);
+            assert_eq!(calldata[..4], selector);
+            assert_eq!(calldata[4..4 + deposit.len()], deposit[..]);
+            assert_eq!(
+                U256::from_be_slice(&calldata[4 + deposit.len()..]),
+                TOKEN_ID
+            );
+        }
+    }
+
     #[test]
     fn test_withdraw_token_succeeds_with_one_key() {
         let options = WITHDRAW_OPTIONS.clone();
@@ -8,7 +8,10 @@ use uniswap_sdk_core::prelude::*;
 pub struct SwapOptions {
     /// How much the execution price is allowed to move unfavorably for the trade execution price.
     pub slippage_tolerance: Percent,
-    /// The account that should receive the output.
+    /// The account that should receive the output. If this is the chain's
+    /// [`ChainAddresses::nonfungible_position_manager`], e.g. to auto-compound a position, native
+    /// output is delivered as the wrapped token since the position manager only accepts ether from
+    /// WETH9.
     pub recipient: Address,
     /// The optional permit parameters for spending the input.
     pub input_token_permit: Option<PermitOptions>,
@@ -16,6 +19,27 @@ pub struct SwapOptions {
     pub sqrt_price_limit_x96: Option<U160>,
     /// Optional information for taking a fee on output.
     pub fee: Option<FeeOptions>,
+    /// The optional unix timestamp after which the transaction will revert, checked by the
+    /// router's `multicall(deadline, data)`.
+    pub deadline: Option<U256>,
+}
+
+impl SwapOptions {
+    /// Sets the deadline to `seconds` after the given current timestamp, saturating at
+    /// `U256::MAX`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `now`: The current unix timestamp, e.g. of the latest block
+    /// * `seconds`: How many seconds from `now` the transaction remains valid
+    #[inline]
+    #[must_use]
+    pub fn deadline_from(self, now: U256, seconds: u64) -> Self {
+        Self {
+            deadline: Some(now.saturating_add(U256::from(seconds))),
+            ..self
+        }
+    }
 }
 
 /// Produces the on-chain method name to call and the hex encoded parameters to pass as arguments
@@ -23,7 +47,8 @@ pub struct SwapOptions {
 ///
 /// ## Notes
 ///
-/// The check on deadline is delegated to [`multicall`](https://github.com/Uniswap/swap-router-contracts/blob/main/contracts/interfaces/IMulticallExtended.sol#L15).
+/// The check on deadline is delegated to [`multicall`](https://github.com/Uniswap/swap-router-contracts/blob/main/contracts/interfaces/IMulticallExtended.sol#L15),
+/// which is only used if [`SwapOptions::deadline`] is set.
 ///
 /// ## Arguments
 ///
@@ -34,6 +59,123 @@ pub fn swap_call_parameters<TInput, TOutput, TP>(
     trades: &mut [Trade<TInput, TOutput, TP>],
     options: SwapOptions,
 ) -> Result<MethodParameters, Error>
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    TP: TickDataProvider,
+{
+    let deadline = options.deadline;
+    let (calldatas, value) = encode_swap_calls(trades, options)?;
+    Ok(MethodParameters {
+        calldata: encode_router_multicall(calldatas, deadline),
+        value,
+    })
+}
+
+/// Same as [`swap_call_parameters`], but also returns the names of the router methods that the
+/// calldata invokes, in the order they are called.
+///
+/// ## Arguments
+///
+/// * `trades`: trades to produce call parameters for
+/// * `options`: options for the call parameters
+#[inline]
+pub fn swap_call_parameters_with_selectors<TInput, TOutput, TP>(
+    trades: &mut [Trade<TInput, TOutput, TP>],
+    options: SwapOptions,
+) -> Result<(MethodParameters, Vec<&'static str>), Error>
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    TP: TickDataProvider,
+{
+    let deadline = options.deadline;
+    let (calldatas, value) = encode_swap_calls(trades, options)?;
+    let selectors = calldatas
+        .iter()
+        .map(|calldata| router_method_name(calldata))
+        .collect();
+    Ok((
+        MethodParameters {
+            calldata: encode_router_multicall(calldatas, deadline),
+            value,
+        },
+        selectors,
+    ))
+}
+
+/// Aggregates the router calls, through the deadline checking `multicall` if a deadline is given
+#[inline]
+fn encode_router_multicall(calldatas: Vec<Bytes>, deadline: Option<U256>) -> Bytes {
+    match deadline {
+        Some(deadline) => IMulticallExtended::multicallCall {
+            deadline,
+            data: calldatas,
+        }
+        .abi_encode()
+        .into(),
+        None => encode_multicall(calldatas),
+    }
+}
+
+/// The methods that [`encode_swap_calls`] may call on the router, keyed by selector.
+const ROUTER_METHODS: [([u8; 4], &str); 11] = [
+    (ISelfPermit::selfPermitCall::SELECTOR, "selfPermit"),
+    (
+        ISelfPermit::selfPermitAllowedCall::SELECTOR,
+        "selfPermitAllowed",
+    ),
+    (
+        IV3SwapRouter::exactInputSingleCall::SELECTOR,
+        "exactInputSingle",
+    ),
+    (IV3SwapRouter::exactInputCall::SELECTOR, "exactInput"),
+    (
+        IV3SwapRouter::exactOutputSingleCall::SELECTOR,
+        "exactOutputSingle",
+    ),
+    (IV3SwapRouter::exactOutputCall::SELECTOR, "exactOutput"),
+    (
+        IPeripheryPaymentsWithFee::unwrapWETH9Call::SELECTOR,
+        "unwrapWETH9",
+    ),
+    (
+        IPeripheryPaymentsWithFee::unwrapWETH9WithFeeCall::SELECTOR,
+        "unwrapWETH9WithFee",
+    ),
+    (
+        IPeripheryPaymentsWithFee::sweepTokenCall::SELECTOR,
+        "sweepToken",
+    ),
+    (
+        IPeripheryPaymentsWithFee::sweepTokenWithFeeCall::SELECTOR,
+        "sweepTokenWithFee",
+    ),
+    (
+        IPeripheryPaymentsWithFee::refundETHCall::SELECTOR,
+        "refundETH",
+    ),
+];
+
+#[inline]
+fn router_method_name(calldata: &[u8]) -> &'static str {
+    ROUTER_METHODS
+        .iter()
+        .find(|(selector, _)| calldata.starts_with(selector))
+        .map(|(_, name)| *name)
+        .unwrap_or_default()
+}
+
+/// Encodes the individual router calls for the given trades
+///
+/// ## Returns
+///
+/// The calldatas to be aggregated in a multicall, and the amount of ether to send
+#[inline]
+fn encode_swap_calls<TInput, TOutput, TP>(
+    trades: &mut [Trade<TInput, TOutput, TP>],
+    options: SwapOptions,
+) -> Result<(Vec<Bytes>, U256), Error>
 where
     TInput: BaseCurrency,
     TOutput: BaseCurrency,
@@ -45,6 +187,7 @@ where
         input_token_permit,
         sqrt_price_limit_x96,
         fee,
+        ..
     } = options;
     let sample_trade = &trades[0];
     let input_currency = sample_trade.input_currency();
@@ -52,7 +195,6 @@ where
     let input_is_native = input_currency.is_native();
     let output_currency = sample_trade.output_currency();
     let token_out = output_currency.wrapped();
-    let output_currency_address = output_currency.address();
     let output_is_native = output_currency.is_native();
     let trade_type = sample_trade.trade_type;
 
@@ -88,8 +230,12 @@ where
 
     // flag for whether a refund needs to happen
     let must_refund = input_is_native && trade_type == TradeType::ExactOutput;
+    // the position manager holds the wrapped token, so the output must not be unwrapped for it
+    let recipient_is_position_manager = chain_addresses(token_in.chain_id())
+        .is_some_and(|addresses| addresses.nonfungible_position_manager == recipient);
+    let unwrap_output = output_is_native && !recipient_is_position_manager;
     // flags for whether funds should be sent first to the router
-    let router_must_custody = output_is_native || fee.is_some();
+    let router_must_custody = unwrap_output || fee.is_some();
 
     let mut total_value = BigInt::ZERO;
     if input_is_native {
@@ -155,7 +301,7 @@ where
             } else {
                 assert!(sqrt_price_limit_x96.is_none(), "MULTIHOP_PRICE_LIMIT");
 
-                let path = encode_route_to_path(route, trade.trade_type == TradeType::ExactOutput);
+                let path = encode_route_to_path(route, trade.trade_type == TradeType::ExactOutput)?;
 
                 calldatas.push(match trade.trade_type {
                     TradeType::ExactInput => IV3SwapRouter::exactInputCall {
@@ -185,11 +331,11 @@ where
 
     // unwrap
     if router_must_custody {
-        if output_is_native {
+        if unwrap_output {
             calldatas.push(encode_unwrap_weth9(total_amount_out, recipient, fee));
         } else {
             calldatas.push(encode_sweep_token(
-                output_currency_address,
+                token_out.address(),
                 total_amount_out,
                 recipient,
                 fee,
@@ -202,10 +348,7 @@ where
         calldatas.push(encode_refund_eth());
     }
 
-    Ok(MethodParameters {
-        calldata: encode_multicall(calldatas),
-        value: U256::from_big_int(total_value),
-    })
+    Ok((calldatas, U256::from_big_int(total_value)))
 }
 
 #[cfg(test)]
@@ -238,8 +381,25 @@ mod tests {
         input_token_permit: None,
         sqrt_price_limit_x96: None,
         fee: None,
+        deadline: None,
     });
 
+    #[test]
+    fn deadline_from_adds_seconds_to_now() {
+        let options = SWAP_OPTIONS
+            .clone()
+            .deadline_from(uint!(1_700_000_000_U256), 1800);
+        assert_eq!(options.deadline, Some(uint!(1_700_001_800_U256)));
+    }
+
+    #[test]
+    fn deadline_from_saturates() {
+        let options = SWAP_OPTIONS
+            .clone()
+            .deadline_from(U256::MAX - uint!(1_U256), 1800);
+        assert_eq!(options.deadline, Some(U256::MAX));
+    }
+
     mod single_trade_input {
         use super::*;
 
@@ -257,6 +417,25 @@ mod tests {
             assert_eq!(value, U256::ZERO);
         }
 
+        #[test]
+        fn single_hop_exact_input_with_deadline() {
+            let trade = Trade::from_route(
+                Route::new(vec![POOL_0_1.clone()], TOKEN0.clone(), TOKEN1.clone()),
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap(),
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            let MethodParameters { calldata, .. } = swap_call_parameters(
+                &mut [trade],
+                SWAP_OPTIONS.clone().deadline_from(uint!(100_U256), 50),
+            )
+            .unwrap();
+            let decoded = IMulticallExtended::multicallCall::abi_decode(&calldata, true).unwrap();
+            assert_eq!(decoded.deadline, uint!(150_U256));
+            assert_eq!(decoded.data.len(), 1);
+            assert_eq!(decoded.data[0].to_vec(), hex!("04e45aaf000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000bb80000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000006400000000000000000000000000000000000000000000000000000000000000610000000000000000000000000000000000000000000000000000000000000000"));
+        }
+
         #[test]
         fn single_hop_exact_output() {
             let trade = Trade::from_route(
@@ -307,6 +486,32 @@ mod tests {
             assert_eq!(value, U256::ZERO);
         }
 
+        #[test]
+        fn multi_hop_exact_output_uses_maximum_amount_in_and_reversed_path() {
+            let trade = Trade::from_route(
+                Route::new(
+                    vec![POOL_0_1.clone(), POOL_1_WETH.clone()],
+                    TOKEN0.clone(),
+                    WETH.clone(),
+                ),
+                CurrencyAmount::from_raw_amount(WETH.clone(), 100).unwrap(),
+                TradeType::ExactOutput,
+            )
+            .unwrap();
+            let MethodParameters { calldata, .. } =
+                swap_call_parameters(&mut [trade], SWAP_OPTIONS.clone()).unwrap();
+            let params = IV3SwapRouter::exactOutputCall::abi_decode(&calldata, true)
+                .unwrap()
+                .params;
+            assert_eq!(params.amountInMaximum, U256::from(105));
+            assert_eq!(params.amountOut, U256::from(100));
+            let (tokens, _) = decode_path(&params.path);
+            assert_eq!(
+                tokens,
+                vec![WETH.address(), TOKEN1.address(), TOKEN0.address()]
+            );
+        }
+
         #[test]
         fn eth_in_exact_input() {
             let trade = Trade::from_route(
@@ -451,6 +656,50 @@ mod tests {
             assert_eq!(calldata.to_vec(), hex!("ac9650d8000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000016000000000000000000000000000000000000000000000000000000000000000e404e45aaf000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000bb800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000a4e0e189a00000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000006100000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000032000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000"));
             assert_eq!(value, U256::ZERO);
         }
+
+        #[test]
+        fn eth_out_to_position_manager_is_not_unwrapped() {
+            let npm = chain_addresses(1).unwrap().nonfungible_position_manager;
+            let trade = Trade::from_route(
+                Route::new(vec![POOL_1_WETH.clone()], TOKEN1.clone(), ETHER.clone()),
+                CurrencyAmount::from_raw_amount(TOKEN1.clone(), 100).unwrap(),
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            let options = SwapOptions {
+                recipient: npm,
+                ..SWAP_OPTIONS.clone()
+            };
+            let (MethodParameters { calldata, value }, selectors) =
+                swap_call_parameters_with_selectors(&mut [trade], options).unwrap();
+            assert_eq!(selectors, vec!["exactInputSingle"]);
+            assert_eq!(value, U256::ZERO);
+            let decoded = IV3SwapRouter::exactInputSingleCall::abi_decode(&calldata, true).unwrap();
+            assert_eq!(decoded.params.recipient, npm);
+            assert_eq!(decoded.params.tokenOut, WETH.address());
+        }
+
+        #[test]
+        fn selectors_for_eth_out_multi_hop_exact_input() {
+            let trade = Trade::from_route(
+                Route::new(
+                    vec![POOL_0_1.clone(), POOL_1_WETH.clone()],
+                    TOKEN0.clone(),
+                    ETHER.clone(),
+                ),
+                CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap(),
+                TradeType::ExactInput,
+            )
+            .unwrap();
+            let (method_parameters, selectors) =
+                swap_call_parameters_with_selectors(&mut [trade.clone()], SWAP_OPTIONS.clone())
+                    .unwrap();
+            assert_eq!(selectors, vec!["exactInput", "unwrapWETH9"]);
+            assert_eq!(
+                method_parameters,
+                swap_call_parameters(&mut [trade], SWAP_OPTIONS.clone()).unwrap()
+            );
+        }
     }
 
     mod multiple_trade_input {
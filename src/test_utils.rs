@@ -0,0 +1,166 @@
+//! ## Test Utilities
+//! Helpers for asserting that the calldata produced by the SDK decodes back to the trades it was
+//! built from.
+
+use crate::prelude::*;
+use alloc::vec;
+use alloy_primitives::{aliases::U24, Bytes, U256};
+use alloy_sol_types::SolCall;
+use uniswap_sdk_core::prelude::*;
+
+/// Builds the swap call parameters for a trade, decodes them back and asserts that every swap
+/// call matches the route, amounts and recipient of the corresponding swap of the trade.
+///
+/// ## Arguments
+///
+/// * `trade`: the trade to round-trip
+/// * `options`: options for the call parameters
+///
+/// ## Panics
+///
+/// Panics if the call parameters cannot be built or a decoded call does not match the trade.
+#[inline]
+pub fn assert_swap_roundtrip<TInput, TOutput, TP>(
+    trade: &mut Trade<TInput, TOutput, TP>,
+    options: SwapOptions,
+) where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    TP: TickDataProvider,
+{
+    let slippage_tolerance = options.slippage_tolerance.clone();
+    let recipient = if options.fee.is_some() || trade.output_currency().is_native() {
+        Address::ZERO
+    } else {
+        options.recipient
+    };
+    let params =
+        swap_call_parameters(core::slice::from_mut(trade), options).expect("CALL_PARAMETERS");
+    let calldatas: Vec<Bytes> = if params
+        .calldata
+        .starts_with(&IMulticall::multicallCall::SELECTOR)
+    {
+        Vec::<Bytes>::decode_multicall(&params.calldata).expect("MULTICALL")
+    } else {
+        vec![params.calldata]
+    };
+
+    let mut swaps = trade.swaps.iter();
+    for calldata in &calldatas {
+        let selector: [u8; 4] = calldata[..4].try_into().unwrap();
+        let (tokens, fees, amount_in, amount_out, call_recipient) = match selector {
+            IV3SwapRouter::exactInputSingleCall::SELECTOR => {
+                let p = IV3SwapRouter::exactInputSingleCall::abi_decode(calldata, true)
+                    .expect("DECODE")
+                    .params;
+                (
+                    vec![p.tokenIn, p.tokenOut],
+                    vec![p.fee],
+                    p.amountIn,
+                    p.amountOutMinimum,
+                    p.recipient,
+                )
+            }
+            IV3SwapRouter::exactOutputSingleCall::SELECTOR => {
+                let p = IV3SwapRouter::exactOutputSingleCall::abi_decode(calldata, true)
+                    .expect("DECODE")
+                    .params;
+                (
+                    vec![p.tokenIn, p.tokenOut],
+                    vec![p.fee],
+                    p.amountInMaximum,
+                    p.amountOut,
+                    p.recipient,
+                )
+            }
+            IV3SwapRouter::exactInputCall::SELECTOR => {
+                let p = IV3SwapRouter::exactInputCall::abi_decode(calldata, true)
+                    .expect("DECODE")
+                    .params;
+                let (tokens, fees) = decode_path(&p.path);
+                (tokens, fees, p.amountIn, p.amountOutMinimum, p.recipient)
+            }
+            IV3SwapRouter::exactOutputCall::SELECTOR => {
+                let p = IV3SwapRouter::exactOutputCall::abi_decode(calldata, true)
+                    .expect("DECODE")
+                    .params;
+                let (mut tokens, mut fees) = decode_path(&p.path);
+                tokens.reverse();
+                fees.reverse();
+                (tokens, fees, p.amountInMaximum, p.amountOut, p.recipient)
+            }
+            _ => continue,
+        };
+        let Swap {
+            route,
+            input_amount,
+            output_amount,
+        } = swaps.next().expect("EXTRA_SWAP");
+
+        let expected_tokens: Vec<Address> = route
+            .token_path()
+            .iter()
+            .map(|token| token.address())
+            .collect();
+        assert_eq!(tokens, expected_tokens, "TOKEN_PATH");
+        let expected_fees: Vec<U24> = route.pools.iter().map(|pool| pool.fee.into()).collect();
+        assert_eq!(fees, expected_fees, "FEES");
+        assert_eq!(
+            amount_in,
+            U256::from_big_int(
+                trade
+                    .maximum_amount_in(slippage_tolerance.clone(), Some(input_amount.clone()))
+                    .expect("MAXIMUM_AMOUNT_IN")
+                    .quotient()
+            ),
+            "AMOUNT_IN"
+        );
+        assert_eq!(
+            amount_out,
+            U256::from_big_int(
+                trade
+                    .minimum_amount_out(slippage_tolerance.clone(), Some(output_amount.clone()))
+                    .expect("MINIMUM_AMOUNT_OUT")
+                    .quotient()
+            ),
+            "AMOUNT_OUT"
+        );
+        assert_eq!(call_recipient, recipient, "RECIPIENT");
+    }
+    assert!(swaps.next().is_none(), "MISSING_SWAP");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+    use alloy_primitives::address;
+    use once_cell::sync::Lazy;
+
+    static POOL_0_1: Lazy<Pool<TickListDataProvider>> =
+        Lazy::new(|| make_pool(TOKEN0.clone(), TOKEN1.clone()));
+    static POOL_1_2: Lazy<Pool<TickListDataProvider>> =
+        Lazy::new(|| make_pool(TOKEN1.clone(), TOKEN2.clone()));
+
+    #[test]
+    fn round_trips_two_hop_exact_input() {
+        let mut trade = Trade::from_route(
+            Route::new(
+                vec![POOL_0_1.clone(), POOL_1_2.clone()],
+                TOKEN0.clone(),
+                TOKEN2.clone(),
+            ),
+            CurrencyAmount::from_raw_amount(TOKEN0.clone(), 100).unwrap(),
+            TradeType::ExactInput,
+        )
+        .unwrap();
+        assert_swap_roundtrip(
+            &mut trade,
+            SwapOptions {
+                slippage_tolerance: Percent::new(1, 100),
+                recipient: address!("0000000000000000000000000000000000000003"),
+                ..Default::default()
+            },
+        );
+    }
+}
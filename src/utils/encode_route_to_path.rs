@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::prelude::{Error, *};
 use alloy_primitives::{aliases::U24, Bytes};
 use alloy_sol_types::SolValue;
 use uniswap_sdk_core::prelude::*;
@@ -7,16 +7,18 @@ use uniswap_sdk_core::prelude::*;
 fn encode_leg<'a, TP: TickDataProvider>(
     pool: &'a Pool<TP>,
     input_token: &'a Token,
-) -> (&'a Token, Vec<u8>) {
+) -> Result<(&'a Token, Vec<u8>), Error> {
     let output_token;
     let leg: (Address, U24) = if pool.token0.equals(input_token) {
         output_token = &pool.token1;
         (pool.token0.address(), pool.fee.into())
-    } else {
+    } else if pool.token1.equals(input_token) {
         output_token = &pool.token0;
         (pool.token1.address(), pool.fee.into())
+    } else {
+        return Err(Error::InvalidRoute);
     };
-    (output_token, leg.abi_encode_packed())
+    Ok((output_token, leg.abi_encode_packed()))
 }
 
 /// Converts a route to a hex encoded path.
@@ -25,35 +27,77 @@ fn encode_leg<'a, TP: TickDataProvider>(
 ///
 /// * `route`: the v3 path to convert to an encoded path
 /// * `exact_output`: whether the route should be encoded in reverse, for making exact output swaps
+///
+/// ## Errors
+///
+/// Returns [`Error::EmptyRoute`] if the route has no pools, and [`Error::InvalidRoute`] if the
+/// pools do not connect the input to the output.
 #[inline]
 pub fn encode_route_to_path<TInput, TOutput, TP>(
     route: &Route<TInput, TOutput, TP>,
     exact_output: bool,
-) -> Bytes
+) -> Result<Bytes, Error>
 where
     TInput: BaseCurrency,
     TOutput: BaseCurrency,
     TP: TickDataProvider,
 {
+    if route.pools.is_empty() {
+        return Err(Error::EmptyRoute);
+    }
     let mut path: Vec<u8> = Vec::with_capacity(23 * route.pools.len() + 20);
     if exact_output {
         let mut output_token = route.output.wrapped();
         for pool in route.pools.iter().rev() {
-            let (input_token, leg) = encode_leg(pool, output_token);
+            let (input_token, leg) = encode_leg(pool, output_token)?;
             output_token = input_token;
             path.extend(leg);
         }
+        if !output_token.equals(route.input.wrapped()) {
+            return Err(Error::InvalidRoute);
+        }
         path.extend(route.input.address().abi_encode_packed());
     } else {
         let mut input_token = route.input.wrapped();
         for pool in &route.pools {
-            let (output_token, leg) = encode_leg(pool, input_token);
+            let (output_token, leg) = encode_leg(pool, input_token)?;
             input_token = output_token;
             path.extend(leg);
         }
+        if !input_token.equals(route.output.wrapped()) {
+            return Err(Error::InvalidRoute);
+        }
         path.extend(route.output.address().abi_encode_packed());
     }
-    path.into()
+    Ok(path.into())
+}
+
+/// Decodes a path produced by [`encode_route_to_path`] into its token addresses and pool fees.
+///
+/// ## Arguments
+///
+/// * `path`: the encoded path, alternating 20 byte addresses and 3 byte fees
+///
+/// ## Returns
+///
+/// The token addresses in path order, and the fees of the pools between them
+#[inline]
+#[must_use]
+pub fn decode_path(path: &[u8]) -> (Vec<Address>, Vec<U24>) {
+    assert!(
+        path.len() >= 20 && (path.len() - 20) % 23 == 0,
+        "PATH_LENGTH"
+    );
+    let num_pools = (path.len() - 20) / 23;
+    let mut tokens = Vec::with_capacity(num_pools + 1);
+    let mut fees = Vec::with_capacity(num_pools);
+    for i in 0..num_pools {
+        let offset = 23 * i;
+        tokens.push(Address::from_slice(&path[offset..offset + 20]));
+        fees.push(U24::from_be_slice(&path[offset + 20..offset + 23]));
+    }
+    tokens.push(Address::from_slice(&path[path.len() - 20..]));
+    (tokens, fees)
 }
 
 #[cfg(test)]
@@ -74,6 +118,17 @@ mod tests {
         .unwrap()
     });
 
+    static POOL_2_3_HIGH: Lazy<Pool> = Lazy::new(|| {
+        Pool::new(
+            TOKEN2.clone(),
+            TOKEN3.clone(),
+            FeeAmount::HIGH,
+            encode_sqrt_ratio_x96(1, 1),
+            0,
+        )
+        .unwrap()
+    });
+
     static ROUTE_0_1: Lazy<Route<Token, Token, NoTickDataProvider>> =
         Lazy::new(|| Route::new(vec![POOL_0_1.clone()], TOKEN0.clone(), TOKEN1.clone()));
     static ROUTE_0_1_2: Lazy<Route<Token, Token, NoTickDataProvider>> = Lazy::new(|| {
@@ -84,6 +139,18 @@ mod tests {
         )
     });
 
+    static ROUTE_0_1_2_3: Lazy<Route<Token, Token, NoTickDataProvider>> = Lazy::new(|| {
+        Route::new(
+            vec![
+                POOL_0_1.clone(),
+                POOL_1_2_LOW.clone(),
+                POOL_2_3_HIGH.clone(),
+            ],
+            TOKEN0.clone(),
+            TOKEN3.clone(),
+        )
+    });
+
     static ROUTE_0_WETH: Lazy<Route<Token, Ether, NoTickDataProvider>> =
         Lazy::new(|| Route::new(vec![POOL_0_WETH.clone()], TOKEN0.clone(), ETHER.clone()));
     static ROUTE_0_1_WETH: Lazy<Route<Token, Ether, NoTickDataProvider>> = Lazy::new(|| {
@@ -106,7 +173,7 @@ mod tests {
     #[test]
     fn pack_them_for_exact_input_single_hop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_1, false).to_vec(),
+            encode_route_to_path(&ROUTE_0_1, false).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000001000bb80000000000000000000000000000000000000002")
         );
     }
@@ -114,7 +181,7 @@ mod tests {
     #[test]
     fn pack_them_for_exact_output_single_hop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_1, true).to_vec(),
+            encode_route_to_path(&ROUTE_0_1, true).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000002000bb80000000000000000000000000000000000000001")
         );
     }
@@ -122,7 +189,7 @@ mod tests {
     #[test]
     fn pack_them_for_exact_input_multihop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_1_2, false).to_vec(),
+            encode_route_to_path(&ROUTE_0_1_2, false).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000001000bb800000000000000000000000000000000000000020001f40000000000000000000000000000000000000003")
         );
     }
@@ -130,15 +197,32 @@ mod tests {
     #[test]
     fn pack_them_for_exact_output_multihop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_1_2, true).to_vec(),
+            encode_route_to_path(&ROUTE_0_1_2, true).unwrap().to_vec(),
             hex!("00000000000000000000000000000000000000030001f40000000000000000000000000000000000000002000bb80000000000000000000000000000000000000001")
         );
     }
 
+    #[test]
+    fn pack_them_for_exact_input_three_hops() {
+        assert_eq!(
+            encode_route_to_path(&ROUTE_0_1_2_3, false).unwrap().to_vec(),
+            hex!("0000000000000000000000000000000000000001000bb800000000000000000000000000000000000000020001f400000000000000000000000000000000000000030027100000000000000000000000000000000000000004")
+        );
+    }
+
+    #[test]
+    fn pack_them_for_exact_output_three_hops() {
+        // exact output paths start at the output token, as expected by `SwapRouter.exactOutput`
+        assert_eq!(
+            encode_route_to_path(&ROUTE_0_1_2_3, true).unwrap().to_vec(),
+            hex!("000000000000000000000000000000000000000400271000000000000000000000000000000000000000030001f40000000000000000000000000000000000000002000bb80000000000000000000000000000000000000001")
+        );
+    }
+
     #[test]
     fn wrap_ether_input_for_exact_input_single_hop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_WETH_0, false).to_vec(),
+            encode_route_to_path(&ROUTE_WETH_0, false).unwrap().to_vec(),
             hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000bb80000000000000000000000000000000000000001")
         );
     }
@@ -146,7 +230,7 @@ mod tests {
     #[test]
     fn wrap_ether_input_for_exact_output_single_hop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_WETH_0, true).to_vec(),
+            encode_route_to_path(&ROUTE_WETH_0, true).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000001000bb8c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
         );
     }
@@ -154,7 +238,7 @@ mod tests {
     #[test]
     fn wrap_ether_input_for_exact_input_multihop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_WETH_0_1, false).to_vec(),
+            encode_route_to_path(&ROUTE_WETH_0_1, false).unwrap().to_vec(),
             hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000bb80000000000000000000000000000000000000001000bb80000000000000000000000000000000000000002")
         );
     }
@@ -162,7 +246,7 @@ mod tests {
     #[test]
     fn wrap_ether_input_for_exact_output_multihop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_WETH_0_1, true).to_vec(),
+            encode_route_to_path(&ROUTE_WETH_0_1, true).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000002000bb80000000000000000000000000000000000000001000bb8c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
         );
     }
@@ -170,7 +254,7 @@ mod tests {
     #[test]
     fn wrap_ether_output_for_exact_input_single_hop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_WETH, false).to_vec(),
+            encode_route_to_path(&ROUTE_0_WETH, false).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000001000bb8c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
         );
     }
@@ -178,7 +262,7 @@ mod tests {
     #[test]
     fn wrap_ether_output_for_exact_output_single_hop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_WETH, true).to_vec(),
+            encode_route_to_path(&ROUTE_0_WETH, true).unwrap().to_vec(),
             hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000bb80000000000000000000000000000000000000001")
         );
     }
@@ -186,7 +270,7 @@ mod tests {
     #[test]
     fn wrap_ether_output_for_exact_input_multihop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_1_WETH, false).to_vec(),
+            encode_route_to_path(&ROUTE_0_1_WETH, false).unwrap().to_vec(),
             hex!("0000000000000000000000000000000000000001000bb80000000000000000000000000000000000000002000bb8c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
         );
     }
@@ -194,8 +278,55 @@ mod tests {
     #[test]
     fn wrap_ether_output_for_exact_output_multihop() {
         assert_eq!(
-            encode_route_to_path(&ROUTE_0_1_WETH, true).to_vec(),
+            encode_route_to_path(&ROUTE_0_1_WETH, true).unwrap().to_vec(),
             hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2000bb80000000000000000000000000000000000000002000bb80000000000000000000000000000000000000001")
         );
     }
+
+    #[test]
+    fn errors_for_empty_route() {
+        let mut route = ROUTE_0_1.clone();
+        route.pools.clear();
+        assert!(matches!(
+            encode_route_to_path(&route, false),
+            Err(Error::EmptyRoute)
+        ));
+        assert!(matches!(
+            encode_route_to_path(&route, true),
+            Err(Error::EmptyRoute)
+        ));
+    }
+
+    #[test]
+    fn errors_for_disconnected_pools() {
+        let mut route = ROUTE_0_1_2.clone();
+        route.pools.reverse();
+        assert!(matches!(
+            encode_route_to_path(&route, false),
+            Err(Error::InvalidRoute)
+        ));
+        assert!(matches!(
+            encode_route_to_path(&route, true),
+            Err(Error::InvalidRoute)
+        ));
+    }
+
+    #[test]
+    fn decode_path_round_trips_multihop() {
+        let (tokens, fees) = decode_path(&encode_route_to_path(&ROUTE_0_1_2, false).unwrap());
+        assert_eq!(
+            tokens,
+            vec![TOKEN0.address(), TOKEN1.address(), TOKEN2.address()]
+        );
+        assert_eq!(
+            fees,
+            vec![U24::from(FeeAmount::MEDIUM), U24::from(FeeAmount::LOW)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "PATH_LENGTH")]
+    fn decode_path_rejects_truncated_path() {
+        decode_path(&hex!("0000000000000000000000000000000000000001000bb8"));
+    }
 }
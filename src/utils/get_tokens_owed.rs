@@ -1,7 +1,11 @@
-use super::Q128;
-use alloy_primitives::Uint;
+use super::{FullMath, Q128};
+use alloy_primitives::{Uint, U256};
 
-/// Computes the amount of fees owed to a position
+/// Computes the amount of fees owed to a position, reproducing `Position.update` of the pool
+/// contract
+///
+/// The fee growth differences wrap around and the owed amounts are truncated to `uint128` like in
+/// the contract.
 #[inline]
 #[must_use]
 pub fn get_tokens_owed<const BITS: usize, const LIMBS: usize>(
@@ -11,19 +15,21 @@ pub fn get_tokens_owed<const BITS: usize, const LIMBS: usize>(
     fee_growth_inside_0_x128: Uint<BITS, LIMBS>,
     fee_growth_inside_1_x128: Uint<BITS, LIMBS>,
 ) -> (Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) {
-    let liquidity = Uint::from(liquidity);
-    let q128 = Uint::from(Q128);
-    let tokens_owed_0 =
-        (fee_growth_inside_0_x128 - fee_growth_inside_0_last_x128) * liquidity / q128;
-    let tokens_owed_1 =
-        (fee_growth_inside_1_x128 - fee_growth_inside_1_last_x128) * liquidity / q128;
-    (tokens_owed_0, tokens_owed_1)
+    let liquidity = U256::from(liquidity);
+    let tokens_owed = |last: Uint<BITS, LIMBS>, current: Uint<BITS, LIMBS>| {
+        // `liquidity < 2^128` so the quotient always fits in 256 bits
+        let owed = current.wrapping_sub(last).mul_div(liquidity, Q128).unwrap();
+        Uint::from(owed.wrapping_to::<u128>())
+    };
+    (
+        tokens_owed(fee_growth_inside_0_last_x128, fee_growth_inside_0_x128),
+        tokens_owed(fee_growth_inside_1_last_x128, fee_growth_inside_1_x128),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::U256;
 
     #[test]
     fn test_get_tokens_owed() {
@@ -31,4 +37,32 @@ mod tests {
         assert_eq!(tokens_owed_0, U256::from(1));
         assert_eq!(tokens_owed_1, U256::from(1));
     }
+
+    #[test]
+    fn test_get_tokens_owed_does_not_overflow_intermediate_product() {
+        let (tokens_owed_0, tokens_owed_1) =
+            get_tokens_owed(U256::ZERO, U256::ZERO, u128::MAX, Q128, Q128 << 1);
+        assert_eq!(tokens_owed_0, U256::from(u128::MAX));
+        // 2 * (2^128 - 1) truncated to `uint128`
+        assert_eq!(tokens_owed_1, U256::from(u128::MAX - 1));
+    }
+
+    #[test]
+    fn test_get_tokens_owed_wraps_around() {
+        assert_eq!(
+            get_tokens_owed(
+                U256::MAX,
+                U256::ZERO.wrapping_sub(Q128),
+                1000,
+                Q128 - U256::from(1),
+                Q128
+            ),
+            (U256::from(1000), U256::from(2000))
+        );
+        // the owed amounts are truncated to `uint128`
+        assert_eq!(
+            get_tokens_owed(U256::from(1), U256::ZERO, u128::MAX, U256::ZERO, U256::ZERO),
+            (U256::from(u128::MAX), U256::ZERO)
+        );
+    }
 }
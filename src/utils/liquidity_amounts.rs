@@ -0,0 +1,357 @@
+//! ## Liquidity Amounts Library in Rust
+//! This library is a Rust port of the [LiquidityAmounts library](https://github.com/Uniswap/v3-periphery/blob/main/contracts/libraries/LiquidityAmounts.sol)
+//! in Solidity, providing functions that compute liquidity amounts from token amounts and prices
+//! without constructing a [`Position`](crate::entities::Position).
+
+use super::Q96;
+use crate::prelude::*;
+use alloy_primitives::{Uint, U256};
+
+#[inline]
+fn sort2<const BITS: usize, const LIMBS: usize>(
+    a: Uint<BITS, LIMBS>,
+    b: Uint<BITS, LIMBS>,
+) -> (U256, U256) {
+    if a > b {
+        (U256::from(b), U256::from(a))
+    } else {
+        (U256::from(a), U256::from(b))
+    }
+}
+
+#[inline]
+fn to_u128(x: U256) -> Result<u128, Error> {
+    u128::try_from(x).map_err(|_| Error::SafeCastToU128Overflow)
+}
+
+/// Computes the amount of liquidity received for a given amount of token0 and price range.
+///
+/// Calculates `amount0 * (sqrt(upper) * sqrt(lower)) / (sqrt(upper) - sqrt(lower))`.
+///
+/// ## Arguments
+///
+/// * `sqrt_ratio_a_x96`: A sqrt price representing the first tick boundary
+/// * `sqrt_ratio_b_x96`: A sqrt price representing the second tick boundary
+/// * `amount0`: The amount0 being sent in
+///
+/// returns: The amount of returned liquidity
+#[inline]
+pub fn get_liquidity_for_amount0<const BITS: usize, const LIMBS: usize>(
+    sqrt_ratio_a_x96: Uint<BITS, LIMBS>,
+    sqrt_ratio_b_x96: Uint<BITS, LIMBS>,
+    amount0: U256,
+) -> Result<u128, Error> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort2(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    let intermediate = sqrt_ratio_a_x96.mul_div(sqrt_ratio_b_x96, Q96)?;
+    to_u128(amount0.mul_div(intermediate, sqrt_ratio_b_x96 - sqrt_ratio_a_x96)?)
+}
+
+/// Computes the amount of liquidity received for a given amount of token1 and price range.
+///
+/// Calculates `amount1 / (sqrt(upper) - sqrt(lower))`.
+///
+/// ## Arguments
+///
+/// * `sqrt_ratio_a_x96`: A sqrt price representing the first tick boundary
+/// * `sqrt_ratio_b_x96`: A sqrt price representing the second tick boundary
+/// * `amount1`: The amount1 being sent in
+///
+/// returns: The amount of returned liquidity
+#[inline]
+pub fn get_liquidity_for_amount1<const BITS: usize, const LIMBS: usize>(
+    sqrt_ratio_a_x96: Uint<BITS, LIMBS>,
+    sqrt_ratio_b_x96: Uint<BITS, LIMBS>,
+    amount1: U256,
+) -> Result<u128, Error> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort2(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    to_u128(amount1.mul_div(Q96, sqrt_ratio_b_x96 - sqrt_ratio_a_x96)?)
+}
+
+/// Computes the maximum amount of liquidity received for a given amount of token0, token1, the
+/// current pool prices and the prices at the tick boundaries.
+///
+/// ## Arguments
+///
+/// * `sqrt_ratio_current_x96`: A sqrt price representing the current pool prices
+/// * `sqrt_ratio_a_x96`: A sqrt price representing the first tick boundary
+/// * `sqrt_ratio_b_x96`: A sqrt price representing the second tick boundary
+/// * `amount0`: The amount of token0 being sent in
+/// * `amount1`: The amount of token1 being sent in
+///
+/// returns: The maximum amount of liquidity received
+#[inline]
+pub fn get_liquidity_for_amounts<const BITS: usize, const LIMBS: usize>(
+    sqrt_ratio_current_x96: Uint<BITS, LIMBS>,
+    mut sqrt_ratio_a_x96: Uint<BITS, LIMBS>,
+    mut sqrt_ratio_b_x96: Uint<BITS, LIMBS>,
+    amount0: U256,
+    amount1: U256,
+) -> Result<u128, Error> {
+    if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = (sqrt_ratio_b_x96, sqrt_ratio_a_x96);
+    }
+
+    if sqrt_ratio_current_x96 <= sqrt_ratio_a_x96 {
+        get_liquidity_for_amount0(sqrt_ratio_a_x96, sqrt_ratio_b_x96, amount0)
+    } else if sqrt_ratio_current_x96 < sqrt_ratio_b_x96 {
+        let liquidity0 =
+            get_liquidity_for_amount0(sqrt_ratio_current_x96, sqrt_ratio_b_x96, amount0)?;
+        let liquidity1 =
+            get_liquidity_for_amount1(sqrt_ratio_a_x96, sqrt_ratio_current_x96, amount1)?;
+        Ok(liquidity0.min(liquidity1))
+    } else {
+        get_liquidity_for_amount1(sqrt_ratio_a_x96, sqrt_ratio_b_x96, amount1)
+    }
+}
+
+/// Computes the amount of token0 for a given amount of liquidity and a price range.
+///
+/// ## Arguments
+///
+/// * `sqrt_ratio_a_x96`: A sqrt price representing the first tick boundary
+/// * `sqrt_ratio_b_x96`: A sqrt price representing the second tick boundary
+/// * `liquidity`: The liquidity being valued
+///
+/// returns: The amount of token0
+#[inline]
+pub fn get_amount0_for_liquidity<const BITS: usize, const LIMBS: usize>(
+    sqrt_ratio_a_x96: Uint<BITS, LIMBS>,
+    sqrt_ratio_b_x96: Uint<BITS, LIMBS>,
+    liquidity: u128,
+) -> Result<U256, Error> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort2(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    Ok((U256::from(liquidity) << 96)
+        .mul_div(sqrt_ratio_b_x96 - sqrt_ratio_a_x96, sqrt_ratio_b_x96)?
+        / sqrt_ratio_a_x96)
+}
+
+/// Computes the amount of token1 for a given amount of liquidity and a price range.
+///
+/// ## Arguments
+///
+/// * `sqrt_ratio_a_x96`: A sqrt price representing the first tick boundary
+/// * `sqrt_ratio_b_x96`: A sqrt price representing the second tick boundary
+/// * `liquidity`: The liquidity being valued
+///
+/// returns: The amount of token1
+#[inline]
+pub fn get_amount1_for_liquidity<const BITS: usize, const LIMBS: usize>(
+    sqrt_ratio_a_x96: Uint<BITS, LIMBS>,
+    sqrt_ratio_b_x96: Uint<BITS, LIMBS>,
+    liquidity: u128,
+) -> Result<U256, Error> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = sort2(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    U256::from(liquidity).mul_div(sqrt_ratio_b_x96 - sqrt_ratio_a_x96, Q96)
+}
+
+/// Computes the token0 and token1 value for a given amount of liquidity, the current pool prices
+/// and the prices at the tick boundaries.
+///
+/// ## Arguments
+///
+/// * `sqrt_ratio_current_x96`: A sqrt price representing the current pool prices
+/// * `sqrt_ratio_a_x96`: A sqrt price representing the first tick boundary
+/// * `sqrt_ratio_b_x96`: A sqrt price representing the second tick boundary
+/// * `liquidity`: The liquidity being valued
+///
+/// returns: The amount of token0 and the amount of token1
+#[inline]
+pub fn get_amounts_for_liquidity<const BITS: usize, const LIMBS: usize>(
+    sqrt_ratio_current_x96: Uint<BITS, LIMBS>,
+    mut sqrt_ratio_a_x96: Uint<BITS, LIMBS>,
+    mut sqrt_ratio_b_x96: Uint<BITS, LIMBS>,
+    liquidity: u128,
+) -> Result<(U256, U256), Error> {
+    if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = (sqrt_ratio_b_x96, sqrt_ratio_a_x96);
+    }
+
+    if sqrt_ratio_current_x96 <= sqrt_ratio_a_x96 {
+        Ok((
+            get_amount0_for_liquidity(sqrt_ratio_a_x96, sqrt_ratio_b_x96, liquidity)?,
+            U256::ZERO,
+        ))
+    } else if sqrt_ratio_current_x96 < sqrt_ratio_b_x96 {
+        Ok((
+            get_amount0_for_liquidity(sqrt_ratio_current_x96, sqrt_ratio_b_x96, liquidity)?,
+            get_amount1_for_liquidity(sqrt_ratio_a_x96, sqrt_ratio_current_x96, liquidity)?,
+        ))
+    } else {
+        Ok((
+            U256::ZERO,
+            get_amount1_for_liquidity(sqrt_ratio_a_x96, sqrt_ratio_b_x96, liquidity)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::encode_sqrt_ratio_x96;
+    use alloy_primitives::U160;
+
+    mod get_liquidity_for_amounts {
+        use super::*;
+
+        fn liquidity(sqrt_price: U160, sqrt_price_a: U160, sqrt_price_b: U160) -> u128 {
+            get_liquidity_for_amounts(
+                sqrt_price,
+                sqrt_price_a,
+                sqrt_price_b,
+                U256::from(100),
+                U256::from(200),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn amounts_for_price_inside() {
+            assert_eq!(
+                liquidity(
+                    encode_sqrt_ratio_x96(1, 1),
+                    encode_sqrt_ratio_x96(100, 110),
+                    encode_sqrt_ratio_x96(110, 100)
+                ),
+                2148
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_below() {
+            assert_eq!(
+                liquidity(
+                    encode_sqrt_ratio_x96(99, 110),
+                    encode_sqrt_ratio_x96(100, 110),
+                    encode_sqrt_ratio_x96(110, 100)
+                ),
+                1048
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_above() {
+            assert_eq!(
+                liquidity(
+                    encode_sqrt_ratio_x96(111, 100),
+                    encode_sqrt_ratio_x96(100, 110),
+                    encode_sqrt_ratio_x96(110, 100)
+                ),
+                2097
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_equal_to_lower_boundary() {
+            let sqrt_price_a = encode_sqrt_ratio_x96(100, 110);
+            assert_eq!(
+                liquidity(sqrt_price_a, sqrt_price_a, encode_sqrt_ratio_x96(110, 100)),
+                1048
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_equal_to_upper_boundary() {
+            let sqrt_price_b = encode_sqrt_ratio_x96(110, 100);
+            assert_eq!(
+                liquidity(sqrt_price_b, encode_sqrt_ratio_x96(100, 110), sqrt_price_b),
+                2097
+            );
+        }
+
+        #[test]
+        fn overflowing_liquidity_is_an_error() {
+            let sqrt_price_a: U160 = encode_sqrt_ratio_x96(100, 110);
+            let sqrt_price_b: U160 = encode_sqrt_ratio_x96(110, 100);
+            assert!(matches!(
+                get_liquidity_for_amounts(
+                    sqrt_price_a,
+                    sqrt_price_a,
+                    sqrt_price_b,
+                    U256::from(u128::MAX),
+                    U256::ZERO,
+                ),
+                Err(Error::SafeCastToU128Overflow)
+            ));
+        }
+    }
+
+    mod get_amounts_for_liquidity {
+        use super::*;
+
+        fn amounts(
+            sqrt_price: U160,
+            sqrt_price_a: U160,
+            sqrt_price_b: U160,
+            liquidity: u128,
+        ) -> (U256, U256) {
+            get_amounts_for_liquidity(sqrt_price, sqrt_price_a, sqrt_price_b, liquidity).unwrap()
+        }
+
+        #[test]
+        fn amounts_for_price_inside() {
+            assert_eq!(
+                amounts(
+                    encode_sqrt_ratio_x96(1, 1),
+                    encode_sqrt_ratio_x96(100, 110),
+                    encode_sqrt_ratio_x96(110, 100),
+                    2148
+                ),
+                (U256::from(99), U256::from(99))
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_below() {
+            assert_eq!(
+                amounts(
+                    encode_sqrt_ratio_x96(99, 110),
+                    encode_sqrt_ratio_x96(100, 110),
+                    encode_sqrt_ratio_x96(110, 100),
+                    1048
+                ),
+                (U256::from(99), U256::ZERO)
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_above() {
+            assert_eq!(
+                amounts(
+                    encode_sqrt_ratio_x96(111, 100),
+                    encode_sqrt_ratio_x96(100, 110),
+                    encode_sqrt_ratio_x96(110, 100),
+                    2097
+                ),
+                (U256::ZERO, U256::from(199))
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_equal_to_lower_boundary() {
+            let sqrt_price_a = encode_sqrt_ratio_x96(100, 110);
+            assert_eq!(
+                amounts(
+                    sqrt_price_a,
+                    sqrt_price_a,
+                    encode_sqrt_ratio_x96(110, 100),
+                    1048
+                ),
+                (U256::from(99), U256::ZERO)
+            );
+        }
+
+        #[test]
+        fn amounts_for_price_equal_to_upper_boundary() {
+            let sqrt_price_b = encode_sqrt_ratio_x96(110, 100);
+            assert_eq!(
+                amounts(
+                    sqrt_price_b,
+                    encode_sqrt_ratio_x96(100, 110),
+                    sqrt_price_b,
+                    2097
+                ),
+                (U256::ZERO, U256::from(199))
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::Error;
+use crate::{entities::Tick, error::Error};
 
 /// Add a signed liquidity delta to liquidity and revert if it overflows or underflows
 ///
@@ -14,3 +14,80 @@ use crate::error::Error;
 pub fn add_delta(x: u128, y: i128) -> Result<u128, Error> {
     x.checked_add_signed(y).ok_or(Error::AddDeltaOverflow)
 }
+
+/// Sums the signed liquidity deltas of the initialized ticks crossed when moving from one tick to
+/// another, i.e. the change in active liquidity between the two ticks
+///
+/// ## Arguments
+///
+/// * `ticks`: The initialized ticks
+/// * `from`: The tick to start from
+/// * `to`: The tick to move to
+///
+/// ## Returns
+///
+/// The net liquidity delta, such that the liquidity at `to` is the liquidity at `from` plus it
+#[inline]
+#[must_use]
+pub fn net_liquidity_between(ticks: &[Tick], from: i32, to: i32) -> i128 {
+    if to >= from {
+        ticks
+            .iter()
+            .filter(|tick| tick.index > from && tick.index <= to)
+            .map(|tick| tick.liquidity_net)
+            .sum()
+    } else {
+        -ticks
+            .iter()
+            .filter(|tick| tick.index > to && tick.index <= from)
+            .map(|tick| tick.liquidity_net)
+            .sum::<i128>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TICKS: [Tick; 4] = [
+        Tick {
+            index: -120,
+            liquidity_gross: 10,
+            liquidity_net: 10,
+        },
+        Tick {
+            index: -60,
+            liquidity_gross: 5,
+            liquidity_net: 5,
+        },
+        Tick {
+            index: 60,
+            liquidity_gross: 5,
+            liquidity_net: -5,
+        },
+        Tick {
+            index: 120,
+            liquidity_gross: 10,
+            liquidity_net: -10,
+        },
+    ];
+
+    #[test]
+    fn net_liquidity_between_upward() {
+        assert_eq!(net_liquidity_between(&TICKS, -200, 0), 15);
+        assert_eq!(net_liquidity_between(&TICKS, 0, 200), -15);
+        assert_eq!(net_liquidity_between(&TICKS, -120, 60), 0);
+    }
+
+    #[test]
+    fn net_liquidity_between_downward() {
+        assert_eq!(net_liquidity_between(&TICKS, 0, -200), -15);
+        assert_eq!(net_liquidity_between(&TICKS, 200, 0), 15);
+        assert_eq!(net_liquidity_between(&TICKS, 60, -121), -10);
+    }
+
+    #[test]
+    fn net_liquidity_between_same_tick() {
+        assert_eq!(net_liquidity_between(&TICKS, 60, 60), 0);
+    }
+}
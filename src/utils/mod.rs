@@ -5,9 +5,11 @@ pub mod encode_sqrt_ratio_x96;
 pub mod full_math;
 pub mod get_fee_growth_inside;
 pub mod get_tokens_owed;
+pub mod liquidity_amounts;
 pub mod liquidity_math;
 pub mod max_liquidity_for_amounts;
 pub mod nearest_usable_tick;
+pub mod percent_from_bps;
 pub mod price_tick_conversions;
 pub mod sqrt_price_math;
 pub mod swap_math;
@@ -16,14 +18,16 @@ pub mod tick_math;
 
 pub use bit_math::*;
 pub use compute_pool_address::compute_pool_address;
-pub use encode_route_to_path::encode_route_to_path;
+pub use encode_route_to_path::{decode_path, encode_route_to_path};
 pub use encode_sqrt_ratio_x96::encode_sqrt_ratio_x96;
 pub use full_math::*;
 pub use get_fee_growth_inside::*;
 pub use get_tokens_owed::get_tokens_owed;
+pub use liquidity_amounts::*;
 pub use liquidity_math::add_delta;
 pub use max_liquidity_for_amounts::*;
 pub use nearest_usable_tick::nearest_usable_tick;
+pub use percent_from_bps::percent_from_bps;
 pub use price_tick_conversions::*;
 pub use sqrt_price_math::*;
 pub use swap_math::*;
@@ -50,3 +54,61 @@ pub struct MethodParameters {
     /// The amount of ether (wei) to send.
     pub value: U256,
 }
+
+impl MethodParameters {
+    /// Returns whether the calldata of both parameters is equal, regardless of their value
+    #[inline]
+    #[must_use]
+    pub fn calldata_eq(&self, other: &Self) -> bool {
+        self.calldata == other.calldata
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl MethodParameters {
+    /// Returns the calldata as a `0x` prefixed hex string
+    #[inline]
+    #[must_use]
+    pub fn calldata_hex(&self) -> alloc::string::String {
+        alloy_primitives::hex::encode_prefixed(&self.calldata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::bytes;
+
+    #[test]
+    fn calldata_eq_ignores_value() {
+        let a = MethodParameters {
+            calldata: bytes!("12345678"),
+            value: U256::ZERO,
+        };
+        let b = MethodParameters {
+            calldata: bytes!("12345678"),
+            value: U256::from(1),
+        };
+        assert_ne!(a, b);
+        assert!(a.calldata_eq(&b));
+        assert!(!a.calldata_eq(&MethodParameters {
+            calldata: bytes!("87654321"),
+            value: U256::ZERO,
+        }));
+    }
+
+    #[test]
+    #[cfg(feature = "fmt")]
+    fn calldata_hex_is_prefixed() {
+        let parameters = MethodParameters {
+            calldata: bytes!("12345678abcd"),
+            value: U256::from(1),
+        };
+        assert_eq!(parameters.calldata_hex(), "0x12345678abcd");
+        let empty = MethodParameters {
+            calldata: Bytes::new(),
+            value: U256::ZERO,
+        };
+        assert_eq!(empty.calldata_hex(), "0x");
+    }
+}
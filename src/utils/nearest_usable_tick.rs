@@ -10,7 +10,8 @@ use num_integer::Integer;
 ///
 /// ## Returns
 ///
-/// The closest tick to the input tick that is usable for the given tick spacing
+/// The closest tick to the input tick that is usable for the given tick spacing, clamped so that it
+/// never rounds past [`MIN_TICK`] or [`MAX_TICK`]
 #[inline]
 pub fn nearest_usable_tick<I: TickIndex>(tick: I, tick_spacing: I) -> I {
     let tick = tick.try_into().unwrap();
@@ -33,7 +34,7 @@ pub fn nearest_usable_tick<I: TickIndex>(tick: I, tick_spacing: I) -> I {
 mod tests {
     use crate::utils::{
         nearest_usable_tick,
-        tick_math::{MAX_TICK, MIN_TICK},
+        tick_math::{MAX_TICK, MAX_TICK_I32, MIN_TICK, MIN_TICK_I32},
     };
     use alloy_primitives::aliases::I24;
 
@@ -95,4 +96,34 @@ mod tests {
         let tick = MAX_TICK / I24::from_limbs([2]) + I24::from_limbs([100]);
         assert_eq!(nearest_usable_tick(MAX_TICK, tick), tick);
     }
+
+    #[test]
+    fn stays_in_range_within_one_spacing_of_max_tick() {
+        for tick_spacing in [1, 10, 60, 200, 16384] {
+            for tick in (MAX_TICK_I32 - tick_spacing)..=MAX_TICK_I32 {
+                let rounded = nearest_usable_tick(tick, tick_spacing);
+                assert!(rounded <= MAX_TICK_I32, "{tick} {tick_spacing}");
+                assert!(
+                    (rounded - tick).abs() < tick_spacing,
+                    "{tick} {tick_spacing}"
+                );
+                assert_eq!(rounded % tick_spacing, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn stays_in_range_within_one_spacing_of_min_tick() {
+        for tick_spacing in [1, 10, 60, 200, 16384] {
+            for tick in MIN_TICK_I32..=(MIN_TICK_I32 + tick_spacing) {
+                let rounded = nearest_usable_tick(tick, tick_spacing);
+                assert!(rounded >= MIN_TICK_I32, "{tick} {tick_spacing}");
+                assert!(
+                    (rounded - tick).abs() < tick_spacing,
+                    "{tick} {tick_spacing}"
+                );
+                assert_eq!(rounded % tick_spacing, 0);
+            }
+        }
+    }
 }
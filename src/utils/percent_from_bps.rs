@@ -0,0 +1,25 @@
+use uniswap_sdk_core::prelude::Percent;
+
+/// Returns the [`Percent`] corresponding to a number of basis points, e.g. `50` for 0.5%.
+///
+/// ## Arguments
+///
+/// * `bps`: The number of basis points
+#[inline]
+#[must_use]
+pub fn percent_from_bps(bps: u32) -> Percent {
+    Percent::new(bps, 10_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uniswap_sdk_core::prelude::BigInt;
+
+    #[test]
+    fn test_percent_from_bps() {
+        // 50 bps is 0.5%, i.e. 1/200
+        let percent = percent_from_bps(50);
+        assert_eq!(percent.numerator * BigInt::from(200), percent.denominator);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use alloc::vec::Vec;
 use alloy_primitives::{aliases::U24, Uint, I256, U160, U256};
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -8,6 +9,31 @@ pub struct SwapState<I = i32> {
     pub sqrt_price_x96: U160,
     pub tick_current: I,
     pub liquidity: u128,
+    /// The fees paid in the input token, accrued to the liquidity providers
+    pub fee_amount: U256,
+    /// The number of initialized ticks crossed by the swap loop, see
+    /// [`Pool::get_output_amount_detailed`] for how it differs from the QuoterV2 count
+    pub initialized_ticks_crossed: u32,
+    /// Whether the swap stopped at `max_ticks_crossed` of [`v3_swap_bounded`] with some of the
+    /// amount specified left to fill
+    pub truncated: bool,
+}
+
+/// A single step of a swap, moving the price within one tick range
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SwapStep<I = i32> {
+    /// The price at the beginning of the step
+    pub sqrt_price_start_x96: U160,
+    /// The next tick to swap to from the current tick in the swap direction
+    pub tick_next: I,
+    /// How much is being swapped in in this step
+    pub amount_in: U256,
+    /// How much is being swapped out
+    pub amount_out: U256,
+    /// How much fee is being paid in
+    pub fee_amount: U256,
+    /// The liquidity in range during the step
+    pub liquidity: u128,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -165,6 +191,99 @@ pub fn v3_swap<TP: TickDataProvider>(
     zero_for_one: bool,
     amount_specified: I256,
     sqrt_price_limit_x96: Option<U160>,
+) -> Result<SwapState<TP::Index>, Error> {
+    v3_swap_bounded(
+        fee,
+        sqrt_price_x96,
+        tick_current,
+        liquidity,
+        tick_spacing,
+        tick_data_provider,
+        zero_for_one,
+        amount_specified,
+        sqrt_price_limit_x96,
+        None,
+    )
+}
+
+/// Same as [`v3_swap`], but stops at the boundary of the next initialized tick once
+/// `max_ticks_crossed` initialized ticks have been crossed, bounding the work done against
+/// adversarial tick data. [`SwapState::truncated`] tells whether the swap was cut short, as
+/// opposed to running out of liquidity or reaching the price limit.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn v3_swap_bounded<TP: TickDataProvider>(
+    fee: U24,
+    sqrt_price_x96: U160,
+    tick_current: TP::Index,
+    liquidity: u128,
+    tick_spacing: TP::Index,
+    tick_data_provider: &TP,
+    zero_for_one: bool,
+    amount_specified: I256,
+    sqrt_price_limit_x96: Option<U160>,
+    max_ticks_crossed: Option<u32>,
+) -> Result<SwapState<TP::Index>, Error> {
+    swap_loop(
+        fee,
+        sqrt_price_x96,
+        tick_current,
+        liquidity,
+        tick_spacing,
+        tick_data_provider,
+        zero_for_one,
+        amount_specified,
+        sqrt_price_limit_x96,
+        max_ticks_crossed,
+        None,
+    )
+}
+
+/// Same as [`v3_swap`], but also returns every step of the swap loop.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn v3_swap_with_trace<TP: TickDataProvider>(
+    fee: U24,
+    sqrt_price_x96: U160,
+    tick_current: TP::Index,
+    liquidity: u128,
+    tick_spacing: TP::Index,
+    tick_data_provider: &TP,
+    zero_for_one: bool,
+    amount_specified: I256,
+    sqrt_price_limit_x96: Option<U160>,
+) -> Result<(SwapState<TP::Index>, Vec<SwapStep<TP::Index>>), Error> {
+    let mut steps = Vec::new();
+    let state = swap_loop(
+        fee,
+        sqrt_price_x96,
+        tick_current,
+        liquidity,
+        tick_spacing,
+        tick_data_provider,
+        zero_for_one,
+        amount_specified,
+        sqrt_price_limit_x96,
+        None,
+        Some(&mut steps),
+    )?;
+    Ok((state, steps))
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn swap_loop<TP: TickDataProvider>(
+    fee: U24,
+    sqrt_price_x96: U160,
+    tick_current: TP::Index,
+    liquidity: u128,
+    tick_spacing: TP::Index,
+    tick_data_provider: &TP,
+    zero_for_one: bool,
+    amount_specified: I256,
+    sqrt_price_limit_x96: Option<U160>,
+    max_ticks_crossed: Option<u32>,
+    mut trace: Option<&mut Vec<SwapStep<TP::Index>>>,
 ) -> Result<SwapState<TP::Index>, Error> {
     let sqrt_price_limit_x96 = sqrt_price_limit_x96.unwrap_or(if zero_for_one {
         MIN_SQRT_RATIO + ONE
@@ -189,6 +308,9 @@ pub fn v3_swap<TP: TickDataProvider>(
         sqrt_price_x96,
         tick_current,
         liquidity,
+        fee_amount: U256::ZERO,
+        initialized_ticks_crossed: 0,
+        truncated: false,
     };
 
     // start swap while loop
@@ -230,6 +352,18 @@ pub fn v3_swap<TP: TickDataProvider>(
             fee,
         )?;
 
+        if let Some(steps) = trace.as_deref_mut() {
+            steps.push(SwapStep {
+                sqrt_price_start_x96: step.sqrt_price_start_x96,
+                tick_next: step.tick_next,
+                amount_in: step.amount_in,
+                amount_out: step.amount_out,
+                fee_amount: step.fee_amount,
+                liquidity: state.liquidity,
+            });
+        }
+
+        state.fee_amount += step.fee_amount;
         if exact_input {
             state.amount_specified_remaining = I256::from_raw(
                 state.amount_specified_remaining.into_raw() - step.amount_in - step.fee_amount,
@@ -247,6 +381,17 @@ pub fn v3_swap<TP: TickDataProvider>(
         if state.sqrt_price_x96 == step.sqrt_price_next_x96 {
             // if the tick is initialized, run the tick transition
             if step.initialized {
+                // stop at the boundary instead of crossing one more tick than allowed
+                if max_ticks_crossed.is_some_and(|max| state.initialized_ticks_crossed >= max) {
+                    state.truncated = !state.amount_specified_remaining.is_zero()
+                        && state.sqrt_price_x96 != sqrt_price_limit_x96;
+                    state.tick_current = if zero_for_one {
+                        step.tick_next
+                    } else {
+                        step.tick_next - TP::Index::ONE
+                    };
+                    break;
+                }
                 let mut liquidity_net = tick_data_provider.get_tick(step.tick_next)?.liquidity_net;
                 // if we're moving leftward, we interpret liquidityNet as the opposite sign
                 // safe because liquidityNet cannot be type(int128).min
@@ -254,6 +399,7 @@ pub fn v3_swap<TP: TickDataProvider>(
                     liquidity_net = -liquidity_net;
                 }
                 state.liquidity = add_delta(state.liquidity, liquidity_net)?;
+                state.initialized_ticks_crossed += 1;
             }
             state.tick_current = if zero_for_one {
                 step.tick_next - TP::Index::ONE
@@ -54,12 +54,13 @@ impl<I: TickIndex> TickList for [Tick<I>] {
 
     #[inline]
     fn is_below_smallest(&self, tick: I) -> bool {
-        tick < self.first().unwrap().index
+        // an empty list has no initialized ticks in either direction
+        self.first().map_or(true, |first| tick < first.index)
     }
 
     #[inline]
     fn is_at_or_above_largest(&self, tick: I) -> bool {
-        tick >= self.last().unwrap().index
+        self.last().map_or(true, |last| tick >= last.index)
     }
 
     #[inline]
@@ -149,6 +150,25 @@ impl<I: TickIndex> TickDataProvider for [Tick<I>] {
             Ok((next_initialized_tick, next_initialized_tick == index))
         }
     }
+
+    #[inline]
+    fn validate_tick_spacing(&self, tick_spacing: I) -> Result<(), Error> {
+        if tick_spacing <= I::ZERO {
+            return Err(Error::InvalidTick(tick_spacing.to_i24_saturating()));
+        }
+        match self
+            .iter()
+            .find(|tick| !(tick.index % tick_spacing).is_zero())
+        {
+            Some(tick) => Err(Error::InvalidTick(tick.index.to_i24_saturating())),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        <[Tick<I>]>::len(self)
+    }
 }
 
 #[cfg(test)]
@@ -218,6 +238,25 @@ mod tests {
         assert!(TICKS.is_at_or_above_largest(MAX_TICK - 1));
     }
 
+    #[test]
+    fn empty_list_has_no_initialized_ticks() {
+        let ticks: [Tick; 0] = [];
+        assert!(ticks.is_below_smallest(0));
+        assert!(ticks.is_at_or_above_largest(0));
+        assert_eq!(
+            ticks
+                .next_initialized_tick_within_one_word(0, true, 1)
+                .unwrap(),
+            (0, false)
+        );
+        assert_eq!(
+            ticks
+                .next_initialized_tick_within_one_word(0, false, 1)
+                .unwrap(),
+            (255, false)
+        );
+    }
+
     mod next_initialized_tick {
         use super::*;
 
@@ -52,7 +52,7 @@ impl<const BITS: usize, const LIMBS: usize> TickMath for Uint<BITS, LIMBS> {
 ///
 /// ## Returns
 ///
-/// The sqrt ratio as a Q64.96
+/// The sqrt ratio as a Q64.96, or [`Error::InvalidTick`] if `|tick| > MAX_TICK`
 #[inline]
 pub fn get_sqrt_ratio_at_tick(tick: I24) -> Result<U160, Error> {
     let abs_tick = tick.abs().as_i32();
@@ -141,6 +141,20 @@ pub fn get_sqrt_ratio_at_tick(tick: I24) -> Result<U160, Error> {
     Ok(U160::from(ratio))
 }
 
+/// Returns the sqrt ratio as a Q64.96 for the given tick, clamping ticks outside of
+/// [`MIN_TICK`, `MAX_TICK`] so that the result saturates at [`MIN_SQRT_RATIO`] or
+/// [`MAX_SQRT_RATIO`] instead of erroring. Intended for display purposes only.
+///
+/// ## Arguments
+///
+/// * `tick`: the tick for which to compute the sqrt ratio
+#[inline]
+#[must_use]
+pub fn get_sqrt_ratio_at_tick_saturating(tick: i32) -> U160 {
+    let tick = I24::try_from(tick.clamp(MIN_TICK_I32, MAX_TICK_I32)).unwrap();
+    get_sqrt_ratio_at_tick(tick).unwrap()
+}
+
 /// Returns the tick corresponding to a given sqrt ratio,
 /// s.t. get_sqrt_ratio_at_tick(tick) <= sqrt_ratio_x96 and get_sqrt_ratio_at_tick(tick + 1) >
 /// sqrt_ratio_x96
@@ -323,6 +337,24 @@ mod tests {
         assert_eq!(get_sqrt_ratio_at_tick(MAX_TICK).unwrap(), MAX_SQRT_RATIO);
     }
 
+    #[test]
+    fn get_sqrt_ratio_at_tick_saturating_clamps_at_both_extremes() {
+        assert_eq!(
+            get_sqrt_ratio_at_tick_saturating(MIN_TICK_I32 - 1),
+            MIN_SQRT_RATIO
+        );
+        assert_eq!(get_sqrt_ratio_at_tick_saturating(i32::MIN), MIN_SQRT_RATIO);
+        assert_eq!(
+            get_sqrt_ratio_at_tick_saturating(MAX_TICK_I32 + 1),
+            MAX_SQRT_RATIO
+        );
+        assert_eq!(get_sqrt_ratio_at_tick_saturating(i32::MAX), MAX_SQRT_RATIO);
+        assert_eq!(
+            get_sqrt_ratio_at_tick_saturating(0),
+            get_sqrt_ratio_at_tick(I24::ZERO).unwrap()
+        );
+    }
+
     #[test]
     fn returns_correct_value_for_sqrt_ratio_at_min_tick() {
         assert_eq!(get_tick_at_sqrt_ratio(MIN_SQRT_RATIO).unwrap(), MIN_TICK);
@@ -335,4 +367,32 @@ mod tests {
             MAX_TICK - I24::ONE
         );
     }
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn get_tick_at_sqrt_ratio_recovers_the_tick(tick in MIN_TICK_I32..=MAX_TICK_I32) {
+                let tick = I24::try_from(tick).unwrap();
+                let sqrt_ratio_x96 = get_sqrt_ratio_at_tick(tick).unwrap();
+                // the max sqrt ratio itself is out of range for `get_tick_at_sqrt_ratio`
+                if tick == MAX_TICK {
+                    assert!(get_tick_at_sqrt_ratio(sqrt_ratio_x96).is_err());
+                } else {
+                    assert_eq!(get_tick_at_sqrt_ratio(sqrt_ratio_x96).unwrap(), tick);
+                }
+            }
+
+            #[test]
+            fn get_sqrt_ratio_at_tick_is_monotonic(tick in MIN_TICK_I32..MAX_TICK_I32) {
+                let tick = I24::try_from(tick).unwrap();
+                assert!(
+                    get_sqrt_ratio_at_tick(tick).unwrap()
+                        < get_sqrt_ratio_at_tick(tick + I24::ONE).unwrap()
+                );
+            }
+        }
+    }
 }